@@ -0,0 +1,138 @@
+//! Compares the word-at-a-time `Eq`, `Ord` and `Hash` implementations with
+//! derived implementations of the same layout
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use qstr::{BStr63, BStr127, FStr64};
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C, align(64))]
+struct DerivedBStr63 {
+  length: u8,
+  data: [u8; 63],
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C, align(128))]
+struct DerivedBStr127 {
+  length: u8,
+  data: [u8; 127],
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C, align(64))]
+struct DerivedFStr64 {
+  data: [u8; 64],
+}
+
+/// Two strings of length `len` that only differ in their last byte
+fn pair(len: usize) -> ([u8; 128], [u8; 128]) {
+  let mut a = [0; 128];
+  let mut b = [0; 128];
+
+  for i in 0..len {
+    a[i] = b'a' + (i % 26) as u8;
+    b[i] = a[i];
+  }
+
+  if len > 0 {
+    b[len - 1] = b'~';
+  }
+
+  (a, b)
+}
+
+fn as_str(bytes: &[u8], len: usize) -> &str {
+  core::str::from_utf8(&bytes[..len]).unwrap()
+}
+
+fn hash<T: Hash>(value: &T) -> u64 {
+  let mut hasher = DefaultHasher::new();
+  value.hash(&mut hasher);
+  hasher.finish()
+}
+
+/// Benchmarks `eq`, `cmp` and `hash` on `a` and `b` for both implementations
+macro_rules! bench_type {
+  ($c:expr, $name:literal, $qstr:expr, $derived:expr) => {{
+    let (a, b) = $qstr;
+    let (da, db) = $derived;
+
+    let mut group = $c.benchmark_group($name);
+    group.bench_function("eq/qstr", |bench| {
+      bench.iter(|| black_box(&a) == black_box(&b))
+    });
+    group.bench_function("eq/derived", |bench| {
+      bench.iter(|| black_box(&da) == black_box(&db))
+    });
+    group.bench_function("cmp/qstr", |bench| {
+      bench.iter(|| black_box(&a).cmp(black_box(&b)))
+    });
+    group.bench_function("cmp/derived", |bench| {
+      bench.iter(|| black_box(&da).cmp(black_box(&db)))
+    });
+    group.bench_function("hash/qstr", |bench| bench.iter(|| hash(black_box(&a))));
+    group.bench_function("hash/derived", |bench| bench.iter(|| hash(black_box(&da))));
+    group.finish();
+  }};
+}
+
+fn bench_compare(c: &mut Criterion) {
+  let (a, b) = pair(60);
+  bench_type!(
+    c,
+    "BStr63",
+    (BStr63::from(as_str(&a, 60)), BStr63::from(as_str(&b, 60))),
+    (
+      DerivedBStr63 {
+        length: 60,
+        data: a[..63].try_into().unwrap(),
+      },
+      DerivedBStr63 {
+        length: 60,
+        data: b[..63].try_into().unwrap(),
+      }
+    )
+  );
+
+  let (a, b) = pair(120);
+  bench_type!(
+    c,
+    "BStr127",
+    (
+      BStr127::from(as_str(&a, 120)),
+      BStr127::from(as_str(&b, 120))
+    ),
+    (
+      DerivedBStr127 {
+        length: 120,
+        data: a[..127].try_into().unwrap(),
+      },
+      DerivedBStr127 {
+        length: 120,
+        data: b[..127].try_into().unwrap(),
+      }
+    )
+  );
+
+  let (a, b) = pair(64);
+  bench_type!(
+    c,
+    "FStr64",
+    (FStr64::from(as_str(&a, 64)), FStr64::from(as_str(&b, 64))),
+    (
+      DerivedFStr64 {
+        data: a[..64].try_into().unwrap(),
+      },
+      DerivedFStr64 {
+        data: b[..64].try_into().unwrap(),
+      }
+    )
+  );
+}
+
+criterion_group!(benches, bench_compare);
+criterion_main!(benches);
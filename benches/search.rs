@@ -0,0 +1,45 @@
+//! Compares the block-wise search and split with `str`
+
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use qstr::{BStr127, StrVec};
+
+const LINE: &str =
+  "2024-01-01T12:00:00Z 10.0.0.1 GET /api/v1/users?id=42&sort=asc 200 1024 0.003 curl/8.5.0";
+
+fn bench_search(c: &mut Criterion) {
+  let s = BStr127::from(LINE);
+
+  let mut group = c.benchmark_group("BStr127");
+  group.bench_function("find/qstr", |bench| {
+    bench.iter(|| black_box(&s).find(black_box("curl")))
+  });
+  group.bench_function("find/str", |bench| {
+    bench.iter(|| black_box(&s).as_str().find(black_box("curl")))
+  });
+  group.bench_function("rfind/qstr", |bench| {
+    bench.iter(|| black_box(&s).rfind(black_box("2024")))
+  });
+  group.bench_function("rfind/str", |bench| {
+    bench.iter(|| black_box(&s).as_str().rfind(black_box("2024")))
+  });
+  group.bench_function("split/qstr", |bench| {
+    bench.iter(|| black_box(&s).split(black_box(" ")))
+  });
+  group.bench_function("split/str", |bench| {
+    bench.iter(|| {
+      let mut vec = StrVec::<u128, 127, qstr::Align128>::new();
+
+      for item in black_box(&s).as_str().split(black_box(" ")) {
+        vec.push(item).unwrap();
+      }
+
+      vec
+    })
+  });
+  group.finish();
+}
+
+criterion_group!(benches, bench_search);
+criterion_main!(benches);
@@ -0,0 +1,52 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use qstr::{BStr7, BStr15, BStr31, BStr63, BStr127};
+
+/// Separates the string from the delimiter. Never occurs in UTF-8.
+const SEPARATOR: u8 = 0xff;
+
+/// Compares `BoundedStr::split` with `str::split`
+macro_rules! check {
+  ($t:ty, $capacity:expr, $s:expr, $delimiter:expr) => {{
+    let Ok(v) = <$t>::try_from($s) else {
+      return;
+    };
+
+    let expected: Vec<&str> = $s.split($delimiter).collect();
+
+    // Empty items occupy a single byte, split() panics if they do not fit
+    if expected.iter().map(|s| s.len().max(1)).sum::<usize>() > $capacity {
+      return;
+    }
+
+    // A single NUL character is indistinguishable from an empty item
+    let expected = expected.into_iter().map(|s| if s == "\0" { "" } else { s });
+
+    assert!(v.split($delimiter).iter().eq(expected));
+  }};
+}
+
+fuzz_target!(|data: &[u8]| {
+  let Some((&selector, rest)) = data.split_first() else {
+    return;
+  };
+
+  let mut parts = rest.splitn(2, |&b| b == SEPARATOR);
+
+  let (Some(s), Some(delimiter)) = (parts.next(), parts.next()) else {
+    return;
+  };
+
+  let (Ok(s), Ok(delimiter)) = (core::str::from_utf8(s), core::str::from_utf8(delimiter)) else {
+    return;
+  };
+
+  match selector % 5 {
+    0 => check!(BStr7, 7, s, delimiter),
+    1 => check!(BStr15, 15, s, delimiter),
+    2 => check!(BStr31, 31, s, delimiter),
+    3 => check!(BStr63, 63, s, delimiter),
+    _ => check!(BStr127, 127, s, delimiter),
+  }
+});
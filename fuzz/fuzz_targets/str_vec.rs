@@ -0,0 +1,80 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use qstr::{ExceedsCapacity, StrVec28, StrVec56, StrVec112};
+
+/// Separates commands. Never occurs in UTF-8.
+const SEPARATOR: u8 = 0xff;
+
+/// Applies `commands` to an empty StrVec as well as a `Vec<String>` model and
+/// checks that both agree after every step
+macro_rules! check {
+  ($t:ty, $capacity:expr, $size:expr, $commands:expr) => {{
+    let mut vec = <$t>::new();
+    let mut model: Vec<String> = Vec::new();
+
+    for command in $commands {
+      match command.split_first() {
+        Some((b'p', arg)) => {
+          let Ok(s) = core::str::from_utf8(arg) else {
+            continue;
+          };
+
+          // Empty items occupy a single byte
+          let length = occupied(&model) + s.len().max(1);
+
+          if length > $capacity {
+            let capacity = $capacity;
+            assert_eq!(vec.push(s), Err(ExceedsCapacity { length, capacity }));
+          } else {
+            assert_eq!(vec.push(s), Ok(()));
+
+            // A single NUL character is indistinguishable from an empty item
+            model.push(if s == "\0" { "" } else { s }.to_string());
+          }
+        }
+        Some((b'g', arg)) => {
+          let index = arg.first().copied().unwrap_or_default() as usize;
+          assert_eq!(vec.get(index), model.get(index).map(String::as_str));
+        }
+        Some((b'c', _)) => {
+          vec.clear();
+          model.clear();
+        }
+        _ => {}
+      }
+
+      assert_eq!(vec.len(), model.len());
+      assert_eq!(vec.is_empty(), model.is_empty());
+      assert!(vec.iter().eq(model.iter().map(String::as_str)));
+
+      for (i, item) in model.iter().enumerate() {
+        assert_eq!(vec.get(i), Some(item.as_str()));
+      }
+
+      assert_eq!(vec.get(model.len()), None);
+
+      let bytes: [u8; $size] = vec.to_bytes();
+      assert_eq!(<$t>::try_from_bytes(&bytes), Ok(vec));
+    }
+  }};
+}
+
+/// Number of bytes occupied by the model's items
+fn occupied(model: &[String]) -> usize {
+  model.iter().map(|s| s.len().max(1)).sum()
+}
+
+fuzz_target!(|data: &[u8]| {
+  let Some((&selector, rest)) = data.split_first() else {
+    return;
+  };
+
+  let commands = rest.split(|&b| b == SEPARATOR);
+
+  match selector % 3 {
+    0 => check!(StrVec28, 28, 32, commands),
+    1 => check!(StrVec56, 56, 64, commands),
+    _ => check!(StrVec112, 112, 128, commands),
+  }
+});
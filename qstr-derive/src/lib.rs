@@ -0,0 +1,175 @@
+//! Derive macros for [qstr](https://docs.rs/qstr)
+//!
+//! This crate is re-exported by qstr when its `derive` feature is enabled and
+//! should not be used directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Fields, Lit, LitStr, parse_macro_input, spanned::Spanned};
+
+/// Derives `parse()` and `write()` for a fixed-width record
+///
+/// See `qstr::FixedRecord` for details.
+#[proc_macro_derive(FixedRecord, attributes(record))]
+pub fn derive_fixed_record(input: TokenStream) -> TokenStream {
+  let input = parse_macro_input!(input as DeriveInput);
+
+  expand(input)
+    .unwrap_or_else(Error::into_compile_error)
+    .into()
+}
+
+/// Padding overrides given by a field's `#[record(...)]` attribute
+#[derive(Default)]
+struct FieldAttrs {
+  pad: Option<u8>,
+  right_aligned: Option<bool>,
+}
+
+impl FieldAttrs {
+  fn parse(field: &syn::Field) -> syn::Result<Self> {
+    let mut attrs = FieldAttrs::default();
+
+    for attr in field.attrs.iter().filter(|a| a.path().is_ident("record")) {
+      attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("pad") {
+          let lit: Lit = meta.value()?.parse()?;
+
+          let byte = match &lit {
+            Lit::Byte(b) => b.value(),
+            Lit::Char(c) if c.value().is_ascii() => c.value() as u8,
+            _ => return Err(Error::new(lit.span(), "expected an ASCII byte or char")),
+          };
+
+          if !byte.is_ascii() {
+            return Err(Error::new(lit.span(), "padding byte must be ASCII"));
+          }
+
+          attrs.pad = Some(byte);
+          Ok(())
+        } else if meta.path.is_ident("align") {
+          let lit: LitStr = meta.value()?.parse()?;
+
+          attrs.right_aligned = match lit.value().as_str() {
+            "left" => Some(false),
+            "right" => Some(true),
+            _ => return Err(Error::new(lit.span(), r#"expected "left" or "right""#)),
+          };
+
+          Ok(())
+        } else {
+          Err(meta.error("expected `pad` or `align`"))
+        }
+      })?;
+    }
+
+    Ok(attrs)
+  }
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+  let name = &input.ident;
+
+  if !input.generics.params.is_empty() {
+    return Err(Error::new(
+      input.generics.span(),
+      "FixedRecord does not support generic structs",
+    ));
+  }
+
+  let Data::Struct(data) = &input.data else {
+    return Err(Error::new(
+      input.ident.span(),
+      "FixedRecord can only be derived for structs",
+    ));
+  };
+
+  if data.fields.is_empty() {
+    return Err(Error::new(
+      input.ident.span(),
+      "FixedRecord requires at least one field",
+    ));
+  }
+
+  let mut size = quote!(0);
+  let mut parsed = Vec::new();
+  let mut written = Vec::new();
+  let mut bindings = Vec::new();
+
+  for (i, field) in data.fields.iter().enumerate() {
+    let ty = &field.ty;
+    let attrs = FieldAttrs::parse(field)?;
+
+    let pad = match attrs.pad {
+      Some(b) => quote!(#b),
+      None => quote!(<#ty as ::qstr::FixedField>::PAD),
+    };
+
+    let right_aligned = match attrs.right_aligned {
+      Some(r) => quote!(#r),
+      None => quote!(<#ty as ::qstr::FixedField>::RIGHT_ALIGNED),
+    };
+
+    let start = size.clone();
+    let end = quote!(#start + <#ty as ::qstr::FixedField>::SIZE);
+
+    let binding = syn::Ident::new(&format!("field{i}"), field.span());
+    let member = match &field.ident {
+      Some(ident) => quote!(#ident),
+      None => {
+        let index = syn::Index::from(i);
+        quote!(#index)
+      }
+    };
+
+    parsed.push(quote! {
+      let #binding = <#ty as ::qstr::FixedField>::parse_field(
+        &bytes[#start..#end],
+        #pad,
+        #right_aligned,
+      )
+      .map_err(|e| e.offset_by(#start))?;
+    });
+
+    written.push(quote! {
+      ::qstr::FixedField::write_field(
+        &self.#member,
+        &mut bytes[#start..#end],
+        #pad,
+        #right_aligned,
+      );
+    });
+
+    bindings.push(binding);
+    size = end;
+  }
+
+  let construct = match &data.fields {
+    Fields::Named(fields) => {
+      let idents = fields.named.iter().map(|f| &f.ident);
+      quote!(Self { #(#idents: #bindings),* })
+    }
+    _ => quote!(Self(#(#bindings),*)),
+  };
+
+  Ok(quote! {
+    impl #name {
+      /// Width of the record in bytes
+      pub const SIZE: usize = #size;
+
+      /// Parses the record from its fixed-width representation
+      pub fn parse(bytes: &[u8; Self::SIZE]) -> ::core::result::Result<Self, ::qstr::InvalidBytes> {
+        #(#parsed)*
+        ::core::result::Result::Ok(#construct)
+      }
+
+      /// Encodes the record in its fixed-width representation
+      pub fn write(&self) -> [u8; Self::SIZE] {
+        let mut bytes = [0u8; Self::SIZE];
+        #(#written)*
+        bytes
+      }
+    }
+  })
+}
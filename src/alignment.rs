@@ -1,24 +1,57 @@
 /// Zero-sized type (ZST) to enforce 8-byte memory alignment
 #[repr(align(8))]
 #[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Debug)]
+#[cfg_attr(
+  feature = "zerocopy",
+  derive(zerocopy::FromBytes, zerocopy::KnownLayout, zerocopy::Immutable)
+)]
 pub struct Align8;
 
 /// Zero-sized type (ZST) to enforce 16-byte memory alignment
 #[repr(align(16))]
 #[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Debug)]
+#[cfg_attr(
+  feature = "zerocopy",
+  derive(zerocopy::FromBytes, zerocopy::KnownLayout, zerocopy::Immutable)
+)]
 pub struct Align16;
 
 /// Zero-sized type (ZST) to enforce 32-byte memory alignment
 #[repr(align(32))]
 #[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Debug)]
+#[cfg_attr(
+  feature = "zerocopy",
+  derive(zerocopy::FromBytes, zerocopy::KnownLayout, zerocopy::Immutable)
+)]
 pub struct Align32;
 
 /// Zero-sized type (ZST) to enforce 64-byte memory alignment
 #[repr(align(64))]
 #[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Debug)]
+#[cfg_attr(
+  feature = "zerocopy",
+  derive(zerocopy::FromBytes, zerocopy::KnownLayout, zerocopy::Immutable)
+)]
 pub struct Align64;
 
 /// Zero-sized type (ZST) to enforce 128-byte memory alignment
 #[repr(align(128))]
 #[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Debug)]
+#[cfg_attr(
+  feature = "zerocopy",
+  derive(zerocopy::FromBytes, zerocopy::KnownLayout, zerocopy::Immutable)
+)]
 pub struct Align128;
+
+// SAFETY: The alignment markers are zero-sized and their alignment is fixed
+//         explicitly, so it does not vary across targets.
+#[cfg(feature = "rkyv")]
+unsafe impl rkyv::Portable for Align8 {}
+#[cfg(feature = "rkyv")]
+unsafe impl rkyv::Portable for Align16 {}
+#[cfg(feature = "rkyv")]
+unsafe impl rkyv::Portable for Align32 {}
+#[cfg(feature = "rkyv")]
+unsafe impl rkyv::Portable for Align64 {}
+#[cfg(feature = "rkyv")]
+unsafe impl rkyv::Portable for Align128 {}
@@ -0,0 +1,273 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Index;
+use core::slice::SliceIndex;
+use core::str::FromStr;
+
+#[cfg(feature = "serde")]
+use std::string::String;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::BoundedStr;
+use crate::ExceedsCapacity;
+use crate::InvalidBytes;
+use crate::fixed_str::check_ascii;
+use crate::ignore_ascii_case::CaseInsensitive;
+use crate::swar;
+
+/// Variable-length ASCII string with a fixed capacity of `N` bytes
+///
+/// Has the same layout as [BoundedStr] and converts to it for free. Since
+/// every byte is a character, characters can be accessed and sliced by their
+/// byte position.
+///
+/// # Usage
+/// ```rust
+/// # use qstr::{Align16, AsciiBoundedStr};
+/// let mut s = AsciiBoundedStr::<15, Align16>::try_from("Hello").unwrap();
+/// assert_eq!(s.char_at(1), Some('e'));
+/// assert_eq!(&s[1..4], "ell");
+///
+/// s.make_ascii_uppercase();
+/// assert!(s.eq_ignore_ascii_case("hello"));
+/// assert!(s.push_str(", wörld").is_err());
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct AsciiBoundedStr<const N: usize, Alignment>(BoundedStr<N, Alignment>);
+
+impl<const N: usize, Alignment> Default for AsciiBoundedStr<N, Alignment> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<const N: usize, Alignment> AsciiBoundedStr<N, Alignment> {
+  /// Create an empty AsciiBoundedStr
+  #[inline]
+  pub const fn new() -> Self {
+    AsciiBoundedStr(BoundedStr::new())
+  }
+
+  /// Constructs AsciiBoundedStr from an ASCII string
+  ///
+  /// Returns `Err` if `s` exceeds the capacity or contains non-ASCII bytes.
+  pub const fn try_from(s: &str) -> Result<Self, InvalidBytes> {
+    if let Err(e) = check_ascii(s.as_bytes()) {
+      return Err(e);
+    }
+
+    match BoundedStr::const_try_from(s) {
+      Some(s) => Ok(AsciiBoundedStr(s)),
+      None => Err(InvalidBytes::ExceedsCapacity(ExceedsCapacity {
+        length: s.len(),
+        capacity: N,
+      })),
+    }
+  }
+
+  /// Checks that a BoundedStr only contains ASCII
+  pub fn try_from_bounded(s: BoundedStr<N, Alignment>) -> Result<Self, InvalidBytes> {
+    check_ascii(s.as_str().as_bytes())?;
+    Ok(AsciiBoundedStr(s))
+  }
+
+  /// Returns string length
+  #[inline]
+  pub fn len(&self) -> usize {
+    self.0.len()
+  }
+
+  /// Checks if the AsciiBoundedStr is empty
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+
+  /// Returns the character at byte position `i`, or `None` if out of bounds
+  #[inline]
+  pub fn char_at(&self, i: usize) -> Option<char> {
+    self.as_bytes().get(i).map(|&b| b as char)
+  }
+
+  /// Returns the substring at the byte range `index`, or `None` if out of
+  /// bounds
+  ///
+  /// Unlike [str::get], every position is a character boundary.
+  #[inline]
+  pub fn get<I: SliceIndex<[u8], Output = [u8]>>(&self, index: I) -> Option<&str> {
+    // SAFETY: Any slice of ASCII bytes is valid UTF-8
+    self
+      .as_bytes()
+      .get(index)
+      .map(|bytes| unsafe { core::str::from_utf8_unchecked(bytes) })
+  }
+
+  /// Appends an ASCII string slice
+  ///
+  /// Returns `Err` and leaves the string unchanged if there is not enough
+  /// capacity or `s` contains non-ASCII bytes.
+  pub fn push_str(&mut self, s: &str) -> Result<(), InvalidBytes> {
+    check_ascii(s.as_bytes()).map_err(|e| e.offset_by(self.len()))?;
+    self.0.push_str(s).map_err(InvalidBytes::ExceedsCapacity)
+  }
+
+  /// Appends a single ASCII character
+  ///
+  /// Returns `Err` if there is not enough capacity or `c` is not ASCII.
+  pub fn push(&mut self, c: char) -> Result<(), InvalidBytes> {
+    let mut buf = [0u8; 4];
+    self.push_str(c.encode_utf8(&mut buf))
+  }
+
+  /// Converts all letters to uppercase in place
+  #[inline]
+  pub fn make_ascii_uppercase(&mut self) {
+    self.0.as_mut_str().make_ascii_uppercase();
+  }
+
+  /// Converts all letters to lowercase in place
+  #[inline]
+  pub fn make_ascii_lowercase(&mut self) {
+    self.0.as_mut_str().make_ascii_lowercase();
+  }
+
+  /// Checks if the content equals `other`, ignoring ASCII case
+  ///
+  /// Compares eight bytes at a time.
+  #[inline]
+  pub fn eq_ignore_ascii_case(&self, other: &str) -> bool {
+    swar::eq_ignore_ascii_case(self.as_str().as_bytes(), other.as_bytes())
+  }
+
+  /// Compares the content with `other` lexicographically, ignoring ASCII case
+  ///
+  /// Compares eight bytes at a time.
+  #[inline]
+  pub fn cmp_ignore_ascii_case(&self, other: &str) -> Ordering {
+    swar::cmp_ignore_ascii_case(self.as_str().as_bytes(), other.as_bytes())
+  }
+
+  /// Returns the content as bytes
+  #[inline]
+  pub fn as_bytes(&self) -> &[u8] {
+    self.as_str().as_bytes()
+  }
+
+  /// Converts AsciiBoundedStr to `&str`
+  #[inline]
+  pub fn as_str(&self) -> &str {
+    self.0.as_str()
+  }
+
+  /// Returns the underlying BoundedStr
+  #[inline]
+  pub fn as_bounded_str(&self) -> &BoundedStr<N, Alignment> {
+    &self.0
+  }
+}
+
+impl<I: SliceIndex<[u8], Output = [u8]>, const N: usize, Alignment> Index<I>
+  for AsciiBoundedStr<N, Alignment>
+{
+  type Output = str;
+
+  #[track_caller]
+  fn index(&self, index: I) -> &str {
+    let bytes = &self.as_bytes()[index];
+
+    // SAFETY: Any slice of ASCII bytes is valid UTF-8
+    unsafe { core::str::from_utf8_unchecked(bytes) }
+  }
+}
+
+impl<const N: usize, Alignment> Hash for AsciiBoundedStr<N, Alignment> {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.as_str().hash(state);
+  }
+}
+
+impl<const N: usize, Alignment> fmt::Display for AsciiBoundedStr<N, Alignment> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+impl<const N: usize, Alignment> fmt::Debug for AsciiBoundedStr<N, Alignment> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+impl<const N: usize, Alignment> CaseInsensitive for AsciiBoundedStr<N, Alignment> {
+  #[inline]
+  fn case_insensitive_eq(&self, other: &Self) -> bool {
+    swar::eq_ignore_ascii_case(self.as_str().as_bytes(), other.as_str().as_bytes())
+  }
+
+  #[inline]
+  fn case_insensitive_cmp(&self, other: &Self) -> Ordering {
+    swar::cmp_ignore_ascii_case(self.as_str().as_bytes(), other.as_str().as_bytes())
+  }
+
+  #[inline]
+  fn case_insensitive_hash<H: Hasher>(&self, state: &mut H) {
+    swar::hash_ignore_ascii_case(self.as_str().as_bytes(), state);
+  }
+}
+
+impl<const N: usize, Alignment> FromStr for AsciiBoundedStr<N, Alignment> {
+  type Err = InvalidBytes;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Self::try_from(s)
+  }
+}
+
+impl<const N: usize, Alignment> TryFrom<&str> for AsciiBoundedStr<N, Alignment> {
+  type Error = InvalidBytes;
+
+  fn try_from(s: &str) -> Result<Self, Self::Error> {
+    Self::try_from(s)
+  }
+}
+
+impl<const N: usize, Alignment> TryFrom<BoundedStr<N, Alignment>>
+  for AsciiBoundedStr<N, Alignment>
+{
+  type Error = InvalidBytes;
+
+  fn try_from(s: BoundedStr<N, Alignment>) -> Result<Self, Self::Error> {
+    Self::try_from_bounded(s)
+  }
+}
+
+impl<const N: usize, Alignment> From<AsciiBoundedStr<N, Alignment>> for BoundedStr<N, Alignment> {
+  fn from(s: AsciiBoundedStr<N, Alignment>) -> Self {
+    s.0
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<const N: usize, Alignment> Serialize for AsciiBoundedStr<N, Alignment> {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    self.as_str().serialize(serializer)
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize, Alignment> Deserialize<'de> for AsciiBoundedStr<N, Alignment> {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: serde::Deserializer<'de>,
+  {
+    let s = String::deserialize(deserializer)?;
+    AsciiBoundedStr::try_from(s.as_str()).map_err(serde::de::Error::custom)
+  }
+}
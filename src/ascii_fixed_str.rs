@@ -0,0 +1,265 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Index;
+use core::slice::SliceIndex;
+use core::str::FromStr;
+
+#[cfg(feature = "serde")]
+use std::string::String;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::FixedStr;
+use crate::InvalidBytes;
+use crate::fixed_str::check_ascii;
+use crate::ignore_ascii_case::CaseInsensitive;
+use crate::swar;
+
+/// Fixed-length ASCII string of `N` bytes, padded with NUL
+///
+/// Has the same layout as [FixedStr] and converts to it for free. Since every
+/// byte is a character, characters can be accessed and sliced by their byte
+/// position, which includes the padding.
+///
+/// # Usage
+/// ```rust
+/// # use qstr::{Align8, AsciiFixedStr};
+/// let mut s = AsciiFixedStr::<8, Align8>::try_from("GBPUSD").unwrap();
+/// assert_eq!(s.char_at(3), Some('U'));
+/// assert_eq!(&s[3..6], "USD");
+///
+/// s.make_ascii_lowercase();
+/// assert_eq!(s.trimmed(), "gbpusd");
+/// assert!(s.eq_ignore_ascii_case("GbpUsd"));
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct AsciiFixedStr<const N: usize, Alignment>(FixedStr<N, Alignment>);
+
+impl<const N: usize, Alignment> Default for AsciiFixedStr<N, Alignment> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<const N: usize, Alignment> AsciiFixedStr<N, Alignment> {
+  /// Creates an AsciiFixedStr consisting of padding only
+  #[inline]
+  pub const fn new() -> Self {
+    AsciiFixedStr(FixedStr::new())
+  }
+
+  /// Constructs AsciiFixedStr from an ASCII string of up to `N` bytes
+  ///
+  /// Returns `Err` if `s` exceeds the capacity or contains non-ASCII bytes.
+  pub const fn try_from(s: &str) -> Result<Self, InvalidBytes> {
+    match FixedStr::try_from_ascii_slice(s.as_bytes()) {
+      Ok(s) => Ok(AsciiFixedStr(s)),
+      Err(e) => Err(e),
+    }
+  }
+
+  /// Constructs AsciiFixedStr from exactly `N` ASCII bytes
+  ///
+  /// Returns `Err` with the offset of the first non-ASCII byte.
+  pub const fn try_from_bytes(bytes: &[u8; N]) -> Result<Self, InvalidBytes> {
+    match FixedStr::try_from_ascii(bytes) {
+      Ok(s) => Ok(AsciiFixedStr(s)),
+      Err(e) => Err(e),
+    }
+  }
+
+  /// Checks that a FixedStr only contains ASCII
+  pub fn try_from_fixed(s: FixedStr<N, Alignment>) -> Result<Self, InvalidBytes> {
+    check_ascii(s.as_bytes())?;
+    Ok(AsciiFixedStr(s))
+  }
+
+  /// Returns the length of the content without the padding
+  #[inline]
+  pub fn len(&self) -> usize {
+    self.0.len()
+  }
+
+  /// Checks if the AsciiFixedStr consists of padding only
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+
+  /// Returns the character at byte position `i`, or `None` if `i >= N`
+  #[inline]
+  pub fn char_at(&self, i: usize) -> Option<char> {
+    self.as_bytes().get(i).map(|&b| b as char)
+  }
+
+  /// Returns the substring at the byte range `index`, or `None` if out of
+  /// bounds
+  ///
+  /// Unlike [str::get], every position is a character boundary.
+  #[inline]
+  pub fn get<I: SliceIndex<[u8], Output = [u8]>>(&self, index: I) -> Option<&str> {
+    // SAFETY: Any slice of ASCII bytes is valid UTF-8
+    self
+      .as_bytes()
+      .get(index)
+      .map(|bytes| unsafe { core::str::from_utf8_unchecked(bytes) })
+  }
+
+  /// Converts all letters to uppercase in place
+  #[inline]
+  pub fn make_ascii_uppercase(&mut self) {
+    self.0.as_mut_str().make_ascii_uppercase();
+  }
+
+  /// Converts all letters to lowercase in place
+  #[inline]
+  pub fn make_ascii_lowercase(&mut self) {
+    self.0.as_mut_str().make_ascii_lowercase();
+  }
+
+  /// Checks if the content without padding equals `other`, ignoring ASCII
+  /// case
+  ///
+  /// Compares eight bytes at a time.
+  #[inline]
+  pub fn eq_ignore_ascii_case(&self, other: &str) -> bool {
+    swar::eq_ignore_ascii_case(self.trimmed().as_bytes(), other.as_bytes())
+  }
+
+  /// Compares the content without padding with `other` lexicographically,
+  /// ignoring ASCII case
+  ///
+  /// Compares eight bytes at a time.
+  #[inline]
+  pub fn cmp_ignore_ascii_case(&self, other: &str) -> Ordering {
+    swar::cmp_ignore_ascii_case(self.trimmed().as_bytes(), other.as_bytes())
+  }
+
+  /// Returns underlying byte buffer
+  #[inline]
+  pub fn as_bytes(&self) -> &[u8; N] {
+    self.0.as_bytes()
+  }
+
+  /// Converts AsciiFixedStr to `&str`, including the padding
+  #[inline]
+  pub fn as_str(&self) -> &str {
+    self.0.as_str()
+  }
+
+  /// Converts AsciiFixedStr to `&str` without the padding
+  #[inline]
+  pub fn trimmed(&self) -> &str {
+    self.0.trimmed()
+  }
+
+  /// Returns the underlying FixedStr
+  #[inline]
+  pub fn as_fixed_str(&self) -> &FixedStr<N, Alignment> {
+    &self.0
+  }
+}
+
+impl<I: SliceIndex<[u8], Output = [u8]>, const N: usize, Alignment> Index<I>
+  for AsciiFixedStr<N, Alignment>
+{
+  type Output = str;
+
+  #[track_caller]
+  fn index(&self, index: I) -> &str {
+    let bytes = &self.as_bytes()[index];
+
+    // SAFETY: Any slice of ASCII bytes is valid UTF-8
+    unsafe { core::str::from_utf8_unchecked(bytes) }
+  }
+}
+
+impl<const N: usize, Alignment> Hash for AsciiFixedStr<N, Alignment> {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.as_str().hash(state);
+  }
+}
+
+impl<const N: usize, Alignment> fmt::Display for AsciiFixedStr<N, Alignment> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt::Display::fmt(&self.0, f)
+  }
+}
+
+impl<const N: usize, Alignment> fmt::Debug for AsciiFixedStr<N, Alignment> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt::Debug::fmt(&self.0, f)
+  }
+}
+
+/// Compares the content without the padding
+impl<const N: usize, Alignment> CaseInsensitive for AsciiFixedStr<N, Alignment> {
+  #[inline]
+  fn case_insensitive_eq(&self, other: &Self) -> bool {
+    swar::eq_ignore_ascii_case(self.trimmed().as_bytes(), other.trimmed().as_bytes())
+  }
+
+  #[inline]
+  fn case_insensitive_cmp(&self, other: &Self) -> Ordering {
+    swar::cmp_ignore_ascii_case(self.trimmed().as_bytes(), other.trimmed().as_bytes())
+  }
+
+  #[inline]
+  fn case_insensitive_hash<H: Hasher>(&self, state: &mut H) {
+    swar::hash_ignore_ascii_case(self.trimmed().as_bytes(), state);
+  }
+}
+
+impl<const N: usize, Alignment> FromStr for AsciiFixedStr<N, Alignment> {
+  type Err = InvalidBytes;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Self::try_from(s)
+  }
+}
+
+impl<const N: usize, Alignment> TryFrom<&str> for AsciiFixedStr<N, Alignment> {
+  type Error = InvalidBytes;
+
+  fn try_from(s: &str) -> Result<Self, Self::Error> {
+    Self::try_from(s)
+  }
+}
+
+impl<const N: usize, Alignment> TryFrom<FixedStr<N, Alignment>> for AsciiFixedStr<N, Alignment> {
+  type Error = InvalidBytes;
+
+  fn try_from(s: FixedStr<N, Alignment>) -> Result<Self, Self::Error> {
+    Self::try_from_fixed(s)
+  }
+}
+
+impl<const N: usize, Alignment> From<AsciiFixedStr<N, Alignment>> for FixedStr<N, Alignment> {
+  fn from(s: AsciiFixedStr<N, Alignment>) -> Self {
+    s.0
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<const N: usize, Alignment> Serialize for AsciiFixedStr<N, Alignment> {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    self.0.serialize(serializer)
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize, Alignment> Deserialize<'de> for AsciiFixedStr<N, Alignment> {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: serde::Deserializer<'de>,
+  {
+    let s = String::deserialize(deserializer)?;
+    AsciiFixedStr::try_from(s.as_str()).map_err(serde::de::Error::custom)
+  }
+}
@@ -1,3 +1,5 @@
+use core::hash::Hash;
+
 /// Bitmap manipulation and traversal functions
 ///
 /// All functions assume that the left-most bit denotes the 0-th bit position.
@@ -28,6 +30,20 @@ where
   /// Counts the number of set bits
   fn count_ones(&self) -> usize;
 
+  /// Writes the bitmap in little-endian byte order
+  ///
+  /// # Safety
+  ///
+  /// Requires that `bytes.len() == BITSIZE / 8`
+  fn write_le(&self, bytes: &mut [u8]);
+
+  /// Reads a bitmap in little-endian byte order
+  ///
+  /// # Safety
+  ///
+  /// Requires that `bytes.len() == BITSIZE / 8`
+  fn read_le(bytes: &[u8]) -> Self;
+
   /// Returns the `(start, end)` range of the n-th span in O(N)
   ///
   /// The bitmap is interpreted as a sequence of spans. Each 1 marks the end of
@@ -67,6 +83,16 @@ macro_rules! impl_bitmap_for {
         <$t>::count_ones(*self) as usize
       }
 
+      #[inline]
+      fn write_le(&self, bytes: &mut [u8]) {
+        bytes.copy_from_slice(&self.to_le_bytes());
+      }
+
+      #[inline]
+      fn read_le(bytes: &[u8]) -> Self {
+        <$t>::from_le_bytes(bytes.try_into().unwrap())
+      }
+
       fn find_nth_span(&self, n: usize) -> Option<(usize, usize)> {
         let mut value = *self;
         let mut start = 0;
@@ -98,3 +124,12 @@ impl_bitmap_for!(u16, 16);
 impl_bitmap_for!(u32, 32);
 impl_bitmap_for!(u64, 64);
 impl_bitmap_for!(u128, 128);
+
+/// Integer storing a [PackedStr](crate::PackedStr) with 6 bits per character
+pub trait PackedBits: Bitmap + Eq + Ord + Hash {
+  /// Number of characters that fit
+  const CAPACITY: usize = Self::BITSIZE / 6;
+}
+
+impl PackedBits for u64 {}
+impl PackedBits for u128 {}
@@ -1,15 +1,29 @@
+use core::cmp::Ordering;
 use core::fmt;
+use core::hash::{Hash, Hasher};
 
 #[cfg(feature = "std")]
 use std::string::String;
 
+#[cfg(feature = "schemars")]
+use alloc::{borrow::Cow, format};
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "rkyv")]
+use rkyv::{Archive, Place, Portable, bytecheck::CheckBytes, munge::munge, rancor::Fallible};
+
 use crate::alignment_resolver::{AlignmentForLength, AlignmentMarker, AlignmentType};
 use crate::bitmap_resolver::{BitmapForLength, BitmapMarker, BitmapType};
-use crate::errors::ExceedsCapacity;
+use crate::errors::{ExceedsCapacity, InvalidBytes};
+use crate::ignore_ascii_case::CaseInsensitive;
+#[cfg(any(feature = "zerocopy", feature = "bytemuck"))]
+use crate::raw::RawBoundedStr;
+use crate::search::{self, MatchIndices, Native};
 use crate::str_vec::StrVec;
+use crate::swar;
+use crate::transform;
 
 #[cfg(doc)]
 use crate::BStr7;
@@ -43,7 +57,12 @@ use crate::FStr64;
 /// capacities of `2ᴺ - 1` for `N ∈ [3, 7]`.
 ///
 /// See also: [BStr7], [BStr15], [BStr31], [BStr63], [BStr127]
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Copy, Clone)]
+#[cfg_attr(
+  feature = "zerocopy",
+  derive(zerocopy::IntoBytes, zerocopy::KnownLayout, zerocopy::Immutable)
+)]
+#[repr(C)]
 pub struct BoundedStr<const N: usize, Alignment> {
   length: u8,
   data: [u8; N],
@@ -56,6 +75,39 @@ impl<const N: usize, Alignment> Default for BoundedStr<N, Alignment> {
   }
 }
 
+/// Compares the length and the data buffer a word at a time
+impl<const N: usize, Alignment> PartialEq for BoundedStr<N, Alignment> {
+  #[inline]
+  fn eq(&self, other: &Self) -> bool {
+    swar::eq_words(self.as_raw_bytes(), other.as_raw_bytes())
+  }
+}
+
+impl<const N: usize, Alignment> Eq for BoundedStr<N, Alignment> {}
+
+impl<const N: usize, Alignment> PartialOrd for BoundedStr<N, Alignment> {
+  #[inline]
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+/// Compares the length and the data buffer a word at a time, such that
+/// shorter strings are ordered first
+impl<const N: usize, Alignment> Ord for BoundedStr<N, Alignment> {
+  #[inline]
+  fn cmp(&self, other: &Self) -> Ordering {
+    swar::cmp_words(self.as_raw_bytes(), other.as_raw_bytes())
+  }
+}
+
+impl<const N: usize, Alignment> Hash for BoundedStr<N, Alignment> {
+  #[inline]
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    swar::hash_words(self.as_raw_bytes(), state);
+  }
+}
+
 impl<const N: usize, Alignment> BoundedStr<N, Alignment> {
   /// Create an empty BoundedStr
   #[inline]
@@ -140,6 +192,130 @@ impl<const N: usize, Alignment> BoundedStr<N, Alignment> {
     })
   }
 
+  /// Constructs a BoundedStr from as many `chars` as fit. If `fill` is set,
+  /// the string is padded to the full capacity.
+  #[cfg(any(feature = "arbitrary", feature = "proptest", feature = "quickcheck"))]
+  pub(crate) fn generate(chars: impl IntoIterator<Item = char>, fill: bool) -> Self {
+    let mut data = [0u8; N];
+    let mut length = crate::generate::write_chars(&mut data, chars);
+
+    if fill {
+      crate::generate::pad(&mut data, 0, length);
+      length = N;
+    }
+
+    BoundedStr {
+      length: length as u8,
+      data,
+      align: [],
+    }
+  }
+
+  /// Checks that `length` and `data` satisfy BoundedStr's invariants
+  ///
+  /// The length must not exceed `N`, the content must be valid UTF-8 and all
+  /// unused bytes must be NUL. Offsets are relative to `data`.
+  pub(crate) fn validate(length: u8, data: &[u8; N]) -> Result<(), InvalidBytes> {
+    let length = length as usize;
+
+    if length > N {
+      return Err(InvalidBytes::ExceedsCapacity(ExceedsCapacity {
+        length,
+        capacity: N,
+      }));
+    }
+
+    if let Err(e) = core::str::from_utf8(&data[..length]) {
+      return Err(InvalidBytes::InvalidUtf8 {
+        offset: e.valid_up_to(),
+      });
+    }
+
+    match data[length..].iter().position(|&b| b != 0) {
+      Some(i) => Err(InvalidBytes::NonZeroPadding { offset: length + i }),
+      None => Ok(()),
+    }
+  }
+
+  /// Casts `bytes` to a BoundedStr reference without copying
+  ///
+  /// `bytes` must match BoundedStr's size and alignment. The length byte and
+  /// content are validated before the reference is returned.
+  #[cfg(feature = "zerocopy")]
+  pub fn try_ref_from_bytes(bytes: &[u8]) -> Result<&Self, InvalidBytes>
+  where
+    Alignment: zerocopy::FromBytes + zerocopy::KnownLayout + zerocopy::Immutable,
+  {
+    let raw = <RawBoundedStr<N, Alignment> as zerocopy::FromBytes>::ref_from_bytes(bytes)
+      .map_err(|_| InvalidBytes::Layout)?;
+
+    Self::validate(raw.length, &raw.data)?;
+
+    // SAFETY: RawBoundedStr has the same layout as BoundedStr, and the
+    //         invariants were checked above
+    Ok(unsafe { &*(raw as *const RawBoundedStr<N, Alignment>).cast::<Self>() })
+  }
+
+  /// Casts `bytes` to a BoundedStr slice without copying
+  ///
+  /// `bytes` must be aligned and its length must be a multiple of BoundedStr's
+  /// size. Every item is validated before the slice is returned.
+  #[cfg(feature = "zerocopy")]
+  pub fn try_slice_from_bytes(bytes: &[u8]) -> Result<&[Self], InvalidBytes>
+  where
+    Alignment: zerocopy::FromBytes + zerocopy::KnownLayout + zerocopy::Immutable,
+  {
+    let raw = <[RawBoundedStr<N, Alignment>] as zerocopy::FromBytes>::ref_from_bytes(bytes)
+      .map_err(|_| InvalidBytes::Layout)?;
+
+    for item in raw {
+      Self::validate(item.length, &item.data)?;
+    }
+
+    // SAFETY: RawBoundedStr has the same layout as BoundedStr, and the
+    //         invariants of every item were checked above
+    Ok(unsafe { core::slice::from_raw_parts(raw.as_ptr().cast::<Self>(), raw.len()) })
+  }
+
+  /// Encodes BoundedStr in the wire format
+  ///
+  /// The first byte holds the length, followed by the NUL-padded content.
+  /// `SIZE` must equal `N + 1` and is usually inferred.
+  ///
+  /// ```rust
+  /// # use qstr::BStr7;
+  /// let bytes: [u8; 8] = BStr7::from("abc").to_bytes();
+  /// assert_eq!(&bytes, b"\x03abc\0\0\0\0");
+  /// ```
+  pub fn to_bytes<const SIZE: usize>(&self) -> [u8; SIZE] {
+    const { assert!(SIZE == N + 1, "SIZE must equal N + 1") };
+
+    let mut bytes = [0u8; SIZE];
+    bytes[0] = self.length;
+    bytes[1..].copy_from_slice(&self.data);
+    bytes
+  }
+
+  /// Decodes BoundedStr from the wire format
+  ///
+  /// Returns `Err` if the length exceeds the capacity, the content is not
+  /// valid UTF-8 or the padding contains non-NUL bytes. Offsets are relative
+  /// to `bytes`.
+  pub fn try_from_bytes<const SIZE: usize>(bytes: &[u8; SIZE]) -> Result<Self, InvalidBytes> {
+    const { assert!(SIZE == N + 1, "SIZE must equal N + 1") };
+
+    let mut data = [0u8; N];
+    data.copy_from_slice(&bytes[1..]);
+
+    Self::validate(bytes[0], &data).map_err(|e| e.offset_by(1))?;
+
+    Ok(BoundedStr {
+      length: bytes[0],
+      data,
+      align: [],
+    })
+  }
+
   /// Returns string length
   #[inline]
   pub fn len(&self) -> usize {
@@ -192,38 +368,203 @@ impl<const N: usize, Alignment> BoundedStr<N, Alignment> {
     unsafe { core::str::from_utf8_unchecked(&self.data[..self.length as usize]) }
   }
 
+  /// Checks if the content equals `other`, ignoring ASCII case
+  ///
+  /// Compares eight bytes at a time.
+  #[inline]
+  pub fn eq_ignore_ascii_case(&self, other: &str) -> bool {
+    swar::eq_ignore_ascii_case(self.as_str().as_bytes(), other.as_bytes())
+  }
+
+  /// Compares the content with `other` lexicographically, ignoring ASCII case
+  ///
+  /// Compares eight bytes at a time.
+  #[inline]
+  pub fn cmp_ignore_ascii_case(&self, other: &str) -> Ordering {
+    swar::cmp_ignore_ascii_case(self.as_str().as_bytes(), other.as_bytes())
+  }
+
+  /// Returns the length byte followed by the data buffer
+  ///
+  /// Unused bytes of the data buffer are always NUL, such that the bytes can
+  /// be compared and hashed as a whole.
+  #[inline]
+  pub(crate) fn as_raw_bytes(&self) -> &[u8] {
+    // SAFETY: BoundedStr is `repr(C)`, so `data` directly follows `length`
+    //         without padding
+    unsafe { core::slice::from_raw_parts((self as *const Self).cast::<u8>(), 1 + N) }
+  }
+
+  /// Converts BoundedStr to `&mut str`, which only permits changes that keep
+  /// the content valid UTF-8
+  #[inline]
+  pub(crate) fn as_mut_str(&mut self) -> &mut str {
+    // SAFETY: See as_str()
+    unsafe { core::str::from_utf8_unchecked_mut(&mut self.data[..self.length as usize]) }
+  }
+
+  /// Returns the content as a [bstr::BStr] for use with [bstr::ByteSlice]
+  #[cfg(feature = "bstr")]
+  #[inline]
+  pub fn as_bstr(&self) -> &bstr::BStr {
+    bstr::BStr::new(self.as_str())
+  }
+
+  /// Returns the byte offset of the first occurrence of `pattern`
+  ///
+  /// Equivalent to [str::find], but scans several bytes at a time.
+  #[inline]
+  pub fn find(&self, pattern: &str) -> Option<usize> {
+    search::find::<Native>(self.as_str().as_bytes(), pattern.as_bytes())
+  }
+
+  /// Returns the byte offset of the last occurrence of `pattern`
+  ///
+  /// Equivalent to [str::rfind], but scans several bytes at a time.
+  #[inline]
+  pub fn rfind(&self, pattern: &str) -> Option<usize> {
+    search::rfind::<Native>(self.as_str().as_bytes(), pattern.as_bytes())
+  }
+
+  /// Checks if `pattern` occurs in BoundedStr
+  #[inline]
+  pub fn contains(&self, pattern: &str) -> bool {
+    self.find(pattern).is_some()
+  }
+
+  /// Iterates over the non-overlapping occurrences of `pattern`
+  ///
+  /// The items match those of [str::matches].
+  pub fn matches<'a>(&'a self, pattern: &'a str) -> impl Iterator<Item = &'a str> {
+    let s = self.as_str();
+    MatchIndices::<Native>::new(s, pattern).map(move |i| &s[i..i + pattern.len()])
+  }
+
+  /// Returns a copy without leading and trailing whitespace
+  ///
+  /// Whitespace is defined as in [str::trim].
+  #[inline]
+  pub fn trim(&self) -> Self {
+    self.with_content(str::trim)
+  }
+
+  /// Returns a copy without leading whitespace
+  #[inline]
+  pub fn trim_start(&self) -> Self {
+    self.with_content(str::trim_start)
+  }
+
+  /// Returns a copy without trailing whitespace
+  #[inline]
+  pub fn trim_end(&self) -> Self {
+    self.with_content(str::trim_end)
+  }
+
+  /// Constructs a BoundedStr from a substring of the content
+  fn with_content(&self, f: impl FnOnce(&str) -> &str) -> Self {
+    let s = f(self.as_str());
+
+    // A substring is not longer than the content and thus fits
+    let mut data = [0u8; N];
+    data[..s.len()].copy_from_slice(s.as_bytes());
+
+    BoundedStr {
+      length: s.len() as u8,
+      data,
+      align: [],
+    }
+  }
+
+  /// Returns a copy with ASCII letters converted to lowercase
+  #[inline]
+  pub fn to_ascii_lowercase(&self) -> Self {
+    let mut data = self.data;
+    data[..self.length as usize].make_ascii_lowercase();
+
+    BoundedStr {
+      length: self.length,
+      data,
+      align: [],
+    }
+  }
+
+  /// Returns a copy with ASCII letters converted to uppercase
+  #[inline]
+  pub fn to_ascii_uppercase(&self) -> Self {
+    let mut data = self.data;
+    data[..self.length as usize].make_ascii_uppercase();
+
+    BoundedStr {
+      length: self.length,
+      data,
+      align: [],
+    }
+  }
+
+  /// Replaces all occurrences of `from` with `to`
+  ///
+  /// The capacity `M` of the result is usually inferred and may differ from
+  /// `N`. Returns `Err` if the result exceeds it.
+  ///
+  /// # Usage
+  /// ```rust
+  /// # use qstr::{BStr7, BStr15};
+  /// let s = BStr7::from("a.b.c.d");
+  /// let r: BStr15 = s.replace(".", "::").unwrap();
+  /// assert_eq!(r.as_str(), "a::b::c::d");
+  ///
+  /// let r: Result<BStr7, _> = s.replace(".", "::");
+  /// assert!(r.is_err());
+  /// ```
+  pub fn replace<const M: usize, B>(
+    &self,
+    from: &str,
+    to: &str,
+  ) -> Result<BoundedStr<M, B>, ExceedsCapacity> {
+    let mut data = [0u8; M];
+    let length = transform::replace_into(self.as_str(), from, to, &mut data)?;
+
+    Ok(BoundedStr {
+      length: length as u8,
+      data,
+      align: [],
+    })
+  }
+
+  /// Repeats the content `n` times
+  ///
+  /// The capacity `M` of the result is usually inferred and may differ from
+  /// `N`. Returns `Err` if the result exceeds it.
+  pub fn repeat<const M: usize, B>(&self, n: usize) -> Result<BoundedStr<M, B>, ExceedsCapacity> {
+    let mut data = [0u8; M];
+    let length = transform::repeat_into(self.as_str(), n, &mut data)?;
+
+    Ok(BoundedStr {
+      length: length as u8,
+      data,
+      align: [],
+    })
+  }
+
   /// Splits BoundedStr by delimiter
   ///
+  /// The items match those of [str::split]. See [StrVec::try_from_split] for
+  /// arbitrary bitmap types and capacities.
+  ///
   /// # Note
   /// This function is only available for common N values (7, 15, 31 etc.) since
   /// the corresponding bitmap size for StrVec is resolved at compile time using a
   /// type-level mapping.
+  ///
+  /// # Panics
+  /// Empty items occupy one byte in a StrVec. Panics if the items do not fit,
+  /// e.g. when the string consists of `N` delimiters.
   pub fn split(&self, delimiter: &str) -> StrVec<BitmapType<N>, N, AlignmentType<N>>
   where
     BitmapMarker: BitmapForLength<N>,
     AlignmentMarker: AlignmentForLength<N>,
   {
-    let s = self.as_str();
-
-    let mut result = StrVec::new();
-    let mut start = 0;
-    let mut i = 0;
-
-    while i < self.length as usize {
-      if &self.data[i..i + delimiter.len()] == delimiter.as_bytes() {
-        result.push(&s[start..i]).unwrap();
-        start = i + delimiter.len();
-        i += delimiter.len();
-      } else {
-        i += 1;
-      }
-    }
-
-    if start <= self.length as usize {
-      result.push(&s[start..]).unwrap();
-    }
-
-    result
+    StrVec::try_from_split(self.as_str(), delimiter).unwrap()
   }
 }
 
@@ -239,6 +580,23 @@ impl<const N: usize, Alignment> fmt::Debug for BoundedStr<N, Alignment> {
   }
 }
 
+impl<const N: usize, Alignment> CaseInsensitive for BoundedStr<N, Alignment> {
+  #[inline]
+  fn case_insensitive_eq(&self, other: &Self) -> bool {
+    swar::eq_ignore_ascii_case(self.as_str().as_bytes(), other.as_str().as_bytes())
+  }
+
+  #[inline]
+  fn case_insensitive_cmp(&self, other: &Self) -> Ordering {
+    swar::cmp_ignore_ascii_case(self.as_str().as_bytes(), other.as_str().as_bytes())
+  }
+
+  #[inline]
+  fn case_insensitive_hash<H: Hasher>(&self, state: &mut H) {
+    swar::hash_ignore_ascii_case(self.as_str().as_bytes(), state);
+  }
+}
+
 impl<const N: usize, Alignment> From<&str> for BoundedStr<N, Alignment> {
   #[track_caller]
   fn from(s: &str) -> Self {
@@ -282,3 +640,486 @@ impl<'de, const N: usize, Alignment> Deserialize<'de> for BoundedStr<N, Alignmen
     BoundedStr::try_from(&s).map_err(serde::de::Error::custom)
   }
 }
+
+// SAFETY: BoundedStr is `repr(C)` and only consists of bytes. Its alignment is
+//         fixed by the `Alignment` marker, which must be `Portable` itself.
+#[cfg(feature = "rkyv")]
+unsafe impl<const N: usize, Alignment: Portable> Portable for BoundedStr<N, Alignment> {}
+
+/// The archived form of a BoundedStr is identical to its in-memory layout and
+/// can be accessed without copying
+#[cfg(feature = "rkyv")]
+impl<const N: usize, Alignment: Portable> Archive for BoundedStr<N, Alignment> {
+  type Archived = Self;
+  type Resolver = ();
+
+  fn resolve(&self, _: Self::Resolver, out: Place<Self::Archived>) {
+    munge!(let BoundedStr { length, data, .. } = out);
+    length.write(self.length);
+    data.write(self.data);
+  }
+}
+
+#[cfg(feature = "rkyv")]
+impl<S, const N: usize, Alignment> rkyv::Serialize<S> for BoundedStr<N, Alignment>
+where
+  S: Fallible + ?Sized,
+  Alignment: Portable,
+{
+  fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+    Ok(())
+  }
+}
+
+#[cfg(feature = "rkyv")]
+impl<D, const N: usize, Alignment> rkyv::Deserialize<Self, D> for BoundedStr<N, Alignment>
+where
+  D: Fallible + ?Sized,
+{
+  fn deserialize(&self, _: &mut D) -> Result<Self, D::Error> {
+    Ok(BoundedStr {
+      length: self.length,
+      data: self.data,
+      align: [],
+    })
+  }
+}
+
+// SAFETY: Every byte pattern is a valid length and data buffer. The remaining
+//         invariants are checked by validate().
+#[cfg(feature = "rkyv")]
+unsafe impl<C, const N: usize, Alignment> CheckBytes<C> for BoundedStr<N, Alignment>
+where
+  C: Fallible + ?Sized,
+  C::Error: rkyv::rancor::Source,
+{
+  unsafe fn check_bytes(value: *const Self, _: &mut C) -> Result<(), C::Error> {
+    let value = unsafe { &*value };
+    Self::validate(value.length, &value.data).map_err(rkyv::rancor::Source::new)
+  }
+}
+
+// SAFETY: An all-zero BoundedStr is the empty string
+#[cfg(feature = "bytemuck")]
+unsafe impl<const N: usize, Alignment> bytemuck::Zeroable for BoundedStr<N, Alignment> {}
+
+// SAFETY: RawBoundedStr has the same layout as BoundedStr. validate() checks
+//         the length byte, UTF-8 validity and NUL padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl<const N: usize, Alignment: Copy + 'static> bytemuck::CheckedBitPattern
+  for BoundedStr<N, Alignment>
+{
+  type Bits = RawBoundedStr<N, Alignment>;
+
+  fn is_valid_bit_pattern(bits: &Self::Bits) -> bool {
+    Self::validate(bits.length, &bits.data).is_ok()
+  }
+}
+
+#[cfg(feature = "borsh")]
+impl<const N: usize, Alignment> borsh::BorshSerialize for BoundedStr<N, Alignment> {
+  fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+    borsh::BorshSerialize::serialize(self.as_str(), writer)
+  }
+}
+
+/// Decodes a borsh string directly into the stack buffer
+#[cfg(feature = "borsh")]
+impl<const N: usize, Alignment> borsh::BorshDeserialize for BoundedStr<N, Alignment> {
+  fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+    use borsh::io::{Error, ErrorKind};
+
+    let length = <u32 as borsh::BorshDeserialize>::deserialize_reader(reader)? as usize;
+
+    if length > N {
+      return Err(Error::new(
+        ErrorKind::InvalidData,
+        "String length exceeds capacity",
+      ));
+    }
+
+    let mut data = [0u8; N];
+    reader.read_exact(&mut data[..length])?;
+
+    if core::str::from_utf8(&data[..length]).is_err() {
+      return Err(Error::new(ErrorKind::InvalidData, "Invalid UTF-8"));
+    }
+
+    Ok(BoundedStr {
+      length: length as u8,
+      data,
+      align: [],
+    })
+  }
+}
+
+#[cfg(feature = "bincode")]
+impl<const N: usize, Alignment> bincode::Encode for BoundedStr<N, Alignment> {
+  fn encode<E: bincode::enc::Encoder>(
+    &self,
+    encoder: &mut E,
+  ) -> Result<(), bincode::error::EncodeError> {
+    bincode::Encode::encode(self.as_str(), encoder)
+  }
+}
+
+/// Decodes a bincode string directly into the stack buffer
+#[cfg(feature = "bincode")]
+impl<Context, const N: usize, Alignment> bincode::Decode<Context> for BoundedStr<N, Alignment> {
+  fn decode<D: bincode::de::Decoder<Context = Context>>(
+    decoder: &mut D,
+  ) -> Result<Self, bincode::error::DecodeError> {
+    use bincode::de::read::Reader;
+    use bincode::error::DecodeError;
+
+    let length = <u64 as bincode::Decode<Context>>::decode(decoder)?;
+
+    if length > N as u64 {
+      return Err(DecodeError::Other("String length exceeds capacity"));
+    }
+
+    let length = length as usize;
+    decoder.claim_bytes_read(length)?;
+
+    let mut data = [0u8; N];
+    decoder.reader().read(&mut data[..length])?;
+
+    if let Err(inner) = core::str::from_utf8(&data[..length]) {
+      return Err(DecodeError::Utf8 { inner });
+    }
+
+    Ok(BoundedStr {
+      length: length as u8,
+      data,
+      align: [],
+    })
+  }
+}
+
+#[cfg(feature = "bincode")]
+impl<'de, Context, const N: usize, Alignment> bincode::BorrowDecode<'de, Context>
+  for BoundedStr<N, Alignment>
+{
+  fn borrow_decode<D: bincode::de::BorrowDecoder<'de, Context = Context>>(
+    decoder: &mut D,
+  ) -> Result<Self, bincode::error::DecodeError> {
+    bincode::Decode::decode(decoder)
+  }
+}
+
+/// Length prefix followed by at most `N` bytes
+#[cfg(feature = "postcard")]
+impl<const N: usize, Alignment> postcard::experimental::max_size::MaxSize
+  for BoundedStr<N, Alignment>
+{
+  const POSTCARD_MAX_SIZE: usize = crate::varint_size(N) + N;
+}
+
+/// A string with `maxLength` set to `N`
+///
+/// Note that `N` limits the UTF-8 encoded length in bytes, whereas JSON Schema
+/// counts characters. Strings with non-ASCII characters may pass the schema,
+/// but still exceed the capacity.
+#[cfg(feature = "schemars")]
+impl<const N: usize, Alignment> schemars::JsonSchema for BoundedStr<N, Alignment> {
+  fn inline_schema() -> bool {
+    true
+  }
+
+  fn schema_name() -> Cow<'static, str> {
+    format!("BoundedStr{N}").into()
+  }
+
+  fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+    schemars::json_schema!({
+      "type": "string",
+      "maxLength": N,
+    })
+  }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, const N: usize, Alignment> arbitrary::Arbitrary<'a> for BoundedStr<N, Alignment> {
+  fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+    let fill = crate::generate::arbitrary_fill(u)?;
+    let chars = crate::generate::arbitrary_chars(u, N)?;
+
+    Ok(Self::generate(chars, fill))
+  }
+}
+
+#[cfg(feature = "proptest")]
+impl<const N: usize, Alignment: 'static> proptest::arbitrary::Arbitrary
+  for BoundedStr<N, Alignment>
+{
+  type Parameters = ();
+  type Strategy = proptest::strategy::BoxedStrategy<Self>;
+
+  fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+    use proptest::strategy::Strategy;
+
+    let chars = proptest::collection::vec(crate::generate::proptest_char(), 0..=N);
+
+    (chars, crate::generate::proptest_fill())
+      .prop_map(|(chars, fill)| Self::generate(chars, fill))
+      .boxed()
+  }
+}
+
+#[cfg(feature = "quickcheck")]
+impl<const N: usize, Alignment: Clone + 'static> quickcheck::Arbitrary
+  for BoundedStr<N, Alignment>
+{
+  fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+    let fill = crate::generate::quickcheck_fill(g);
+    Self::generate(crate::generate::quickcheck_chars(g, N), fill)
+  }
+
+  fn shrink(&self) -> alloc::boxed::Box<dyn Iterator<Item = Self>> {
+    alloc::boxed::Box::new(
+      quickcheck::Arbitrary::shrink(&alloc::string::String::from(self.as_str()))
+        .map(|s| Self::generate(s.chars(), false)),
+    )
+  }
+}
+
+#[cfg(feature = "defmt")]
+impl<const N: usize, Alignment> defmt::Format for BoundedStr<N, Alignment> {
+  fn format(&self, f: defmt::Formatter) {
+    defmt::write!(f, "{=str}", self.as_str())
+  }
+}
+
+#[cfg(feature = "ufmt")]
+impl<const N: usize, Alignment> ufmt::uDisplay for BoundedStr<N, Alignment> {
+  fn fmt<W: ufmt::uWrite + ?Sized>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error> {
+    f.write_str(self.as_str())
+  }
+}
+
+#[cfg(feature = "ufmt")]
+impl<const N: usize, Alignment> ufmt::uDebug for BoundedStr<N, Alignment> {
+  fn fmt<W: ufmt::uWrite + ?Sized>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error> {
+    f.write_str(self.as_str())
+  }
+}
+
+/// Allows formatting into a BoundedStr with `uwrite!`. Fails without writing
+/// anything if a string slice does not fit.
+#[cfg(feature = "ufmt")]
+impl<const N: usize, Alignment> ufmt::uWrite for BoundedStr<N, Alignment> {
+  type Error = ExceedsCapacity;
+
+  fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+    self.push_str(s)
+  }
+}
+
+/// Fallible conversions between any capacities, in place of [TryFrom]
+///
+/// The [From] impls exist for every combination of capacities, so [TryFrom]
+/// impls would conflict with them.
+#[cfg(feature = "heapless")]
+impl<const N: usize, Alignment> BoundedStr<N, Alignment> {
+  /// Creates a BoundedStr from a `heapless::String` of any capacity
+  ///
+  /// ```rust
+  /// # use qstr::BStr7;
+  /// let s: heapless::String<16> = heapless::String::try_from("abcdefgh").unwrap();
+  /// assert!(BStr7::try_from_heapless(&s).is_err());
+  /// ```
+  pub fn try_from_heapless<const M: usize>(
+    s: &heapless::String<M>,
+  ) -> Result<Self, ExceedsCapacity> {
+    Self::try_from(s.as_str())
+  }
+
+  /// Converts BoundedStr into a `heapless::String` of any capacity
+  pub fn try_into_heapless<const M: usize>(&self) -> Result<heapless::String<M>, ExceedsCapacity> {
+    heapless::String::try_from(self.as_str()).map_err(|_| ExceedsCapacity {
+      length: self.len(),
+      capacity: M,
+    })
+  }
+}
+
+/// Infallible conversion. Requires `M <= N`.
+///
+/// The impl exists for all capacities. Others are only rejected once the
+/// conversion is built, e.g. by `cargo build` but not `cargo check`. Use
+/// [BoundedStr::try_from_heapless] for them instead.
+///
+/// ```compile_fail
+/// # use qstr::BStr7;
+/// let s: heapless::String<8> = heapless::String::new();
+/// let _ = BStr7::from(s);
+/// ```
+#[cfg(feature = "heapless")]
+impl<const N: usize, const M: usize, Alignment> From<heapless::String<M>>
+  for BoundedStr<N, Alignment>
+{
+  fn from(s: heapless::String<M>) -> Self {
+    const { assert!(M <= N, "BoundedStr capacity must not be below the source's") };
+    Self::try_from(s.as_str()).unwrap()
+  }
+}
+
+/// Infallible conversion. Requires `N <= M`.
+///
+/// The impl exists for all capacities. Others are only rejected once the
+/// conversion is built, e.g. by `cargo build` but not `cargo check`. Use
+/// [BoundedStr::try_into_heapless] for them instead.
+#[cfg(feature = "heapless")]
+impl<const N: usize, const M: usize, Alignment> From<BoundedStr<N, Alignment>>
+  for heapless::String<M>
+{
+  fn from(s: BoundedStr<N, Alignment>) -> Self {
+    const { assert!(N <= M, "Target capacity must not be below BoundedStr's") };
+
+    let mut result = Self::new();
+    result.push_str(s.as_str()).unwrap();
+    result
+  }
+}
+
+/// Fallible conversions between any capacities, in place of [TryFrom]
+///
+/// The [From] impls exist for every combination of capacities, so [TryFrom]
+/// impls would conflict with them.
+#[cfg(feature = "arrayvec")]
+impl<const N: usize, Alignment> BoundedStr<N, Alignment> {
+  /// Creates a BoundedStr from an `arrayvec::ArrayString` of any capacity
+  pub fn try_from_arrayvec<const M: usize>(
+    s: &arrayvec::ArrayString<M>,
+  ) -> Result<Self, ExceedsCapacity> {
+    Self::try_from(s.as_str())
+  }
+
+  /// Converts BoundedStr into an `arrayvec::ArrayString` of any capacity
+  pub fn try_into_arrayvec<const M: usize>(
+    &self,
+  ) -> Result<arrayvec::ArrayString<M>, ExceedsCapacity> {
+    arrayvec::ArrayString::from(self.as_str()).map_err(|_| ExceedsCapacity {
+      length: self.len(),
+      capacity: M,
+    })
+  }
+}
+
+/// Infallible conversion. Requires `M <= N`.
+///
+/// The impl exists for all capacities. Others are only rejected once the
+/// conversion is built, e.g. by `cargo build` but not `cargo check`. Use
+/// [BoundedStr::try_from_arrayvec] for them instead.
+#[cfg(feature = "arrayvec")]
+impl<const N: usize, const M: usize, Alignment> From<arrayvec::ArrayString<M>>
+  for BoundedStr<N, Alignment>
+{
+  fn from(s: arrayvec::ArrayString<M>) -> Self {
+    const { assert!(M <= N, "BoundedStr capacity must not be below the source's") };
+    Self::try_from(s.as_str()).unwrap()
+  }
+}
+
+/// Infallible conversion. Requires `N <= M`.
+///
+/// The impl exists for all capacities. Others are only rejected once the
+/// conversion is built, e.g. by `cargo build` but not `cargo check`. Use
+/// [BoundedStr::try_into_arrayvec] for them instead.
+#[cfg(feature = "arrayvec")]
+impl<const N: usize, const M: usize, Alignment> From<BoundedStr<N, Alignment>>
+  for arrayvec::ArrayString<M>
+{
+  fn from(s: BoundedStr<N, Alignment>) -> Self {
+    const { assert!(N <= M, "Target capacity must not be below BoundedStr's") };
+
+    let mut result = Self::new();
+    result.push_str(s.as_str());
+    result
+  }
+}
+
+/// Converts the value. Strings of up to 23 bytes are stored inline
+/// without allocating.
+#[cfg(feature = "smol_str")]
+impl<const N: usize, Alignment> From<BoundedStr<N, Alignment>> for smol_str::SmolStr {
+  fn from(s: BoundedStr<N, Alignment>) -> Self {
+    smol_str::SmolStr::new(s.as_str())
+  }
+}
+
+#[cfg(feature = "smol_str")]
+impl<const N: usize, Alignment> TryFrom<&smol_str::SmolStr> for BoundedStr<N, Alignment> {
+  type Error = ExceedsCapacity;
+
+  fn try_from(s: &smol_str::SmolStr) -> Result<Self, Self::Error> {
+    Self::try_from(s.as_str())
+  }
+}
+
+#[cfg(feature = "smol_str")]
+impl<const N: usize, Alignment> PartialEq<smol_str::SmolStr> for BoundedStr<N, Alignment> {
+  fn eq(&self, other: &smol_str::SmolStr) -> bool {
+    self.as_str() == other.as_str()
+  }
+}
+
+#[cfg(feature = "smol_str")]
+impl<const N: usize, Alignment> PartialEq<BoundedStr<N, Alignment>> for smol_str::SmolStr {
+  fn eq(&self, other: &BoundedStr<N, Alignment>) -> bool {
+    self.as_str() == other.as_str()
+  }
+}
+
+/// Converts the value. Strings of up to 24 bytes are stored inline
+/// without allocating.
+#[cfg(feature = "compact_str")]
+impl<const N: usize, Alignment> From<BoundedStr<N, Alignment>> for compact_str::CompactString {
+  fn from(s: BoundedStr<N, Alignment>) -> Self {
+    compact_str::CompactString::new(s.as_str())
+  }
+}
+
+#[cfg(feature = "compact_str")]
+impl<const N: usize, Alignment> TryFrom<&compact_str::CompactString> for BoundedStr<N, Alignment> {
+  type Error = ExceedsCapacity;
+
+  fn try_from(s: &compact_str::CompactString) -> Result<Self, Self::Error> {
+    Self::try_from(s.as_str())
+  }
+}
+
+#[cfg(feature = "compact_str")]
+impl<const N: usize, Alignment> PartialEq<compact_str::CompactString> for BoundedStr<N, Alignment> {
+  fn eq(&self, other: &compact_str::CompactString) -> bool {
+    self.as_str() == other.as_str()
+  }
+}
+
+#[cfg(feature = "compact_str")]
+impl<const N: usize, Alignment> PartialEq<BoundedStr<N, Alignment>> for compact_str::CompactString {
+  fn eq(&self, other: &BoundedStr<N, Alignment>) -> bool {
+    self.as_str() == other.as_str()
+  }
+}
+
+#[cfg(feature = "bstr")]
+impl<const N: usize, Alignment> AsRef<bstr::BStr> for BoundedStr<N, Alignment> {
+  fn as_ref(&self) -> &bstr::BStr {
+    self.as_bstr()
+  }
+}
+
+#[cfg(feature = "bstr")]
+impl<const N: usize, Alignment> PartialEq<bstr::BStr> for BoundedStr<N, Alignment> {
+  fn eq(&self, other: &bstr::BStr) -> bool {
+    other == self.as_str()
+  }
+}
+
+#[cfg(feature = "bstr")]
+impl<const N: usize, Alignment> PartialEq<BoundedStr<N, Alignment>> for bstr::BStr {
+  fn eq(&self, other: &BoundedStr<N, Alignment>) -> bool {
+    self == other.as_str()
+  }
+}
@@ -0,0 +1,189 @@
+//! Hex, base32 and base64 codecs (RFC 4648) used by [FixedStr](crate::FixedStr)
+//!
+//! All encodings are handled by a single bit-oriented codec, since they only
+//! differ in the number of bits per character, the alphabet and the padding.
+
+use crate::DecodeError;
+
+/// Character used for padding
+const PAD: u8 = b'=';
+
+/// Marks bytes outside the alphabet in a decoding table
+const INVALID: u8 = 0xff;
+
+pub(crate) struct Alphabet {
+  /// Characters indexed by their value
+  chars: &'static [u8],
+
+  /// Values indexed by their character
+  values: [u8; 256],
+
+  /// Number of bits encoded per character
+  bits: usize,
+
+  /// Number of characters per group, which padding completes
+  group: usize,
+
+  padded: bool,
+}
+
+impl Alphabet {
+  const fn new(chars: &'static [u8], bits: usize, group: usize, padded: bool) -> Self {
+    let mut values = [INVALID; 256];
+    let mut i = 0;
+
+    while i < chars.len() {
+      values[chars[i] as usize] = i as u8;
+      i += 1;
+    }
+
+    Alphabet {
+      chars,
+      values,
+      bits,
+      group,
+      padded,
+    }
+  }
+
+  /// Additionally accepts `chars` when decoding
+  const fn with_aliases(mut self, chars: &'static [u8]) -> Self {
+    let mut i = 0;
+
+    while i < chars.len() {
+      self.values[chars[i] as usize] = i as u8;
+      i += 1;
+    }
+
+    self
+  }
+
+  /// Number of characters without padding for `m` input bytes
+  const fn data_len(&self, m: usize) -> usize {
+    (m * 8).div_ceil(self.bits)
+  }
+
+  /// Number of characters including padding for `m` input bytes
+  pub(crate) const fn encoded_len(&self, m: usize) -> usize {
+    if self.padded {
+      self.data_len(m).div_ceil(self.group) * self.group
+    } else {
+      self.data_len(m)
+    }
+  }
+
+  /// Encodes `input` to `out`, which must be `encoded_len(input.len())` bytes
+  /// long
+  pub(crate) fn encode(&self, input: &[u8], out: &mut [u8]) {
+    let mask = (1 << self.bits) - 1;
+    let mut buffer = 0u32;
+    let mut buffered = 0;
+    let mut offset = 0;
+
+    for &byte in input {
+      buffer = (buffer << 8) | byte as u32;
+      buffered += 8;
+
+      while buffered >= self.bits {
+        buffered -= self.bits;
+        out[offset] = self.chars[((buffer >> buffered) & mask) as usize];
+        offset += 1;
+      }
+    }
+
+    if buffered > 0 {
+      out[offset] = self.chars[((buffer << (self.bits - buffered)) & mask) as usize];
+      offset += 1;
+    }
+
+    out[offset..].fill(PAD);
+  }
+
+  /// Decodes `input` to `out`, which determines the expected length
+  ///
+  /// Unused bits of the last character must be zero, such that every value
+  /// has exactly one encoding.
+  pub(crate) fn decode(&self, input: &[u8], out: &mut [u8]) -> Result<(), DecodeError> {
+    let expected = self.encoded_len(out.len());
+
+    if input.len() != expected {
+      return Err(DecodeError::InvalidLength {
+        length: input.len(),
+        expected,
+      });
+    }
+
+    let data_len = self.data_len(out.len());
+
+    if let Some(i) = input[data_len..].iter().position(|&c| c != PAD) {
+      return Err(DecodeError::InvalidPadding {
+        offset: data_len + i,
+      });
+    }
+
+    let mut buffer = 0u32;
+    let mut buffered = 0;
+    let mut offset = 0;
+
+    for (i, &c) in input[..data_len].iter().enumerate() {
+      let value = self.values[c as usize];
+
+      if value == INVALID {
+        return Err(DecodeError::InvalidCharacter { offset: i });
+      }
+
+      buffer = (buffer << self.bits) | value as u32;
+      buffered += self.bits;
+
+      if buffered >= 8 {
+        buffered -= 8;
+        out[offset] = (buffer >> buffered) as u8;
+        offset += 1;
+      }
+    }
+
+    if buffer & ((1 << buffered) - 1) != 0 {
+      return Err(DecodeError::InvalidCharacter {
+        offset: data_len - 1,
+      });
+    }
+
+    Ok(())
+  }
+}
+
+/// Lowercase hexadecimal digit for the value `v`, which must be below 16
+pub(crate) const fn hex_digit(v: u8) -> u8 {
+  HEX.chars[v as usize]
+}
+
+/// Value of the hexadecimal digit `c` in either case
+pub(crate) const fn hex_value(c: u8) -> Option<u8> {
+  match HEX.values[c as usize] {
+    INVALID => None,
+    v => Some(v),
+  }
+}
+
+/// Lowercase hexadecimal. Decoding also accepts uppercase digits.
+pub(crate) const HEX: Alphabet =
+  Alphabet::new(b"0123456789abcdef", 4, 2, false).with_aliases(b"0123456789ABCDEF");
+
+/// Base32 with padding
+pub(crate) const BASE32: Alphabet = Alphabet::new(b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567", 5, 8, true);
+
+/// Base64 with padding
+pub(crate) const BASE64: Alphabet = Alphabet::new(
+  b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+  6,
+  4,
+  true,
+);
+
+/// URL-safe base64 without padding
+pub(crate) const BASE64_URL: Alphabet = Alphabet::new(
+  b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_",
+  6,
+  4,
+  false,
+);
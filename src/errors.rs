@@ -30,3 +30,182 @@ impl fmt::Display for ExceedsCapacity {
 }
 
 impl Error for ExceedsCapacity {}
+
+/// Byte representation violates a type's invariants
+#[derive(PartialEq, Eq)]
+pub enum InvalidBytes {
+  /// Encoded length exceeds capacity
+  ExceedsCapacity(ExceedsCapacity),
+
+  /// Bytes starting at `offset` are not valid UTF-8
+  InvalidUtf8 {
+    /// Offset of the first invalid byte
+    offset: usize,
+  },
+
+  /// Byte at `offset` is not ASCII
+  NonAscii {
+    /// Offset of the non-ASCII byte
+    offset: usize,
+  },
+
+  /// Unused byte at `offset` is not NUL
+  NonZeroPadding {
+    /// Offset of the non-zero byte
+    offset: usize,
+  },
+
+  /// Bitmap marks positions beyond the capacity
+  InvalidBitmap,
+
+  /// Byte slice does not match the type's size or alignment
+  Layout,
+}
+
+impl InvalidBytes {
+  /// Shifts the reported offset by `n` bytes
+  pub fn offset_by(self, n: usize) -> Self {
+    match self {
+      InvalidBytes::InvalidUtf8 { offset } => InvalidBytes::InvalidUtf8 { offset: offset + n },
+      InvalidBytes::NonAscii { offset } => InvalidBytes::NonAscii { offset: offset + n },
+      InvalidBytes::NonZeroPadding { offset } => {
+        InvalidBytes::NonZeroPadding { offset: offset + n }
+      }
+      e => e,
+    }
+  }
+}
+
+impl fmt::Debug for InvalidBytes {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt::Display::fmt(self, f)
+  }
+}
+
+impl fmt::Display for InvalidBytes {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      InvalidBytes::ExceedsCapacity(e) => fmt::Display::fmt(e, f),
+      InvalidBytes::InvalidUtf8 { offset } => {
+        f.write_fmt(format_args!("Invalid UTF-8 at offset {}", offset))
+      }
+      InvalidBytes::NonAscii { offset } => {
+        f.write_fmt(format_args!("Non-ASCII byte at offset {}", offset))
+      }
+      InvalidBytes::NonZeroPadding { offset } => {
+        f.write_fmt(format_args!("Non-zero padding at offset {}", offset))
+      }
+      InvalidBytes::InvalidBitmap => f.write_str("Bitmap exceeds capacity"),
+      InvalidBytes::Layout => f.write_str("Size or alignment mismatch"),
+    }
+  }
+}
+
+impl Error for InvalidBytes {}
+
+/// Encoded string is not valid for the requested encoding
+#[derive(PartialEq, Eq)]
+pub enum DecodeError {
+  /// Length does not match the encoded length of the output
+  InvalidLength {
+    /// Length of the encoded string
+    length: usize,
+
+    /// Expected length
+    expected: usize,
+  },
+
+  /// Character at `offset` is not part of the alphabet, or has non-zero
+  /// unused bits
+  InvalidCharacter {
+    /// Offset of the invalid character
+    offset: usize,
+  },
+
+  /// Padding character expected at `offset`
+  InvalidPadding {
+    /// Offset of the invalid character
+    offset: usize,
+  },
+}
+
+impl fmt::Debug for DecodeError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt::Display::fmt(self, f)
+  }
+}
+
+impl fmt::Display for DecodeError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      DecodeError::InvalidLength { length, expected } => f.write_fmt(format_args!(
+        "Encoded length ({}) does not match expected length ({})",
+        length, expected
+      )),
+      DecodeError::InvalidCharacter { offset } => {
+        f.write_fmt(format_args!("Invalid character at offset {}", offset))
+      }
+      DecodeError::InvalidPadding { offset } => {
+        f.write_fmt(format_args!("Invalid padding at offset {}", offset))
+      }
+    }
+  }
+}
+
+impl Error for DecodeError {}
+
+/// String rejected by a [Validator](crate::Validator)
+#[derive(PartialEq, Eq)]
+pub struct InvalidStr {
+  /// Offset of the first offending byte, which equals the string's length if
+  /// it is too short
+  pub offset: usize,
+}
+
+impl fmt::Debug for InvalidStr {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt::Display::fmt(self, f)
+  }
+}
+
+impl fmt::Display for InvalidStr {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_fmt(format_args!("Invalid string at offset {}", self.offset))
+  }
+}
+
+impl Error for InvalidStr {}
+
+/// String exceeds the capacity or is rejected by a
+/// [Validator](crate::Validator) or a type's character set
+#[derive(PartialEq, Eq)]
+pub enum ValidationError<E> {
+  /// String length exceeds capacity
+  ExceedsCapacity(ExceedsCapacity),
+
+  /// String was rejected by the validator
+  Invalid(E),
+}
+
+impl<E> From<ExceedsCapacity> for ValidationError<E> {
+  fn from(e: ExceedsCapacity) -> Self {
+    ValidationError::ExceedsCapacity(e)
+  }
+}
+
+impl<E: fmt::Display> fmt::Debug for ValidationError<E> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt::Display::fmt(self, f)
+  }
+}
+
+impl<E: fmt::Display> fmt::Display for ValidationError<E> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ValidationError::ExceedsCapacity(e) => fmt::Display::fmt(e, f),
+      ValidationError::Invalid(e) => fmt::Display::fmt(e, f),
+    }
+  }
+}
+
+impl<E: fmt::Display> Error for ValidationError<E> {}
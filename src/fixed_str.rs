@@ -1,11 +1,21 @@
+use core::cmp::Ordering;
 use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::Range;
 
 #[cfg(feature = "std")]
 use std::string::String;
 
+#[cfg(feature = "schemars")]
+use alloc::{borrow::Cow, format};
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "rkyv")]
+use rkyv::{Archive, Place, Portable, bytecheck::CheckBytes, munge::munge, rancor::Fallible};
+
 #[cfg(doc)]
 use crate::BStr63;
 #[cfg(doc)]
@@ -24,8 +34,19 @@ use crate::FStr32;
 use crate::FStr64;
 #[cfg(doc)]
 use crate::FStr128;
+#[cfg(doc)]
+use crate::Pad;
 
+use crate::DecodeError;
 use crate::ExceedsCapacity;
+use crate::InvalidBytes;
+use crate::codec::{self, Alphabet};
+use crate::ignore_ascii_case::CaseInsensitive;
+use crate::padding::{Nul, Padding};
+#[cfg(any(feature = "zerocopy", feature = "bytemuck"))]
+use crate::raw::RawFixedStr;
+use crate::swar;
+use crate::transform;
 
 /// Fixed stack-allocated string
 ///
@@ -35,36 +56,84 @@ use crate::ExceedsCapacity;
 ///
 /// Unlike [BoundedStr], the length is not encoded and is assumed to match the
 /// capacity `N`. However, if the string is shorter than `N`, the remaining
-/// bytes will be NUL-padded.
+/// bytes will be padded, with NULs by default.
+///
+/// # Padding
+/// The padding policy `P` selects the padding byte and alignment, see [Pad].
+/// Fixed-width protocols often pad with spaces or right-align numbers:
+/// ```rust
+/// # use qstr::{Align16, FixedStr, RightZero, Space};
+/// let name: FixedStr<8, Align16, Space> = "ACME".into();
+/// assert_eq!(name.to_string(), "ACME    ");
+/// assert_eq!(name.trimmed(), "ACME");
+///
+/// let amount: FixedStr<8, Align16, RightZero> = "1250".into();
+/// assert_eq!(amount.to_string(), "00001250");
+/// assert_eq!(amount.len(), 4);
+/// ```
 ///
 /// # Aliases
 /// See also: [FStr8], [FStr16], [FStr24], [FStr32], [FStr64], [FStr128]
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct FixedStr<const N: usize, Alignment> {
+#[derive(Copy, Clone)]
+#[cfg_attr(
+  feature = "zerocopy",
+  derive(zerocopy::IntoBytes, zerocopy::KnownLayout, zerocopy::Immutable)
+)]
+#[repr(C)]
+pub struct FixedStr<const N: usize, Alignment, P = Nul> {
   data: [u8; N],
   align: [Alignment; 0],
+  padding: PhantomData<P>,
 }
 
-impl<const N: usize, Alignment> FixedStr<N, Alignment> {
-  /// Creates a NUL-padded FixedStr. Equivalent to FixedStr::default().
+impl<const N: usize, Alignment, P: Padding> FixedStr<N, Alignment, P> {
+  /// Creates a FixedStr consisting of padding only. Equivalent to
+  /// FixedStr::default().
   #[inline]
   pub const fn new() -> Self {
     FixedStr {
-      data: [0; N],
+      data: [P::BYTE; N],
       align: [],
+      padding: PhantomData,
     }
   }
 
   /// # Safety
   /// This function requires that the provided bytes can be represented by a UTF-8 string.
   /// Otherwise, [Self::as_str] and [Self::as_str_trimmed] are not well-defined.
+  ///
+  /// See [Self::try_from_bytes], [Self::try_from_slice] and
+  /// [Self::try_from_ascii] for checked alternatives.
   #[inline]
   pub const unsafe fn from_bytes(data: [u8; N]) -> Self {
-    FixedStr { data, align: [] }
+    FixedStr {
+      data,
+      align: [],
+      padding: PhantomData,
+    }
+  }
+
+  /// Constructs a FixedStr from as many `chars` as fit. If `fill` is set, the
+  /// string is filled to the full capacity rather than padded.
+  #[cfg(any(feature = "arbitrary", feature = "proptest", feature = "quickcheck"))]
+  pub(crate) fn generate(chars: impl IntoIterator<Item = char>, fill: bool) -> Self {
+    let mut buf = [0u8; N];
+    let mut length = crate::generate::write_chars(&mut buf, chars);
+
+    if fill {
+      crate::generate::pad(&mut buf, 0, length);
+      length = N;
+    }
+
+    FixedStr {
+      data: Self::pad(&buf[..length]),
+      align: [],
+      padding: PhantomData,
+    }
   }
 
   /// It is possible to construct a FixedStr shorter than its capacity, in which
-  /// case the missing bytes will be filled with NULs.
+  /// case the missing bytes will be filled with padding.
   #[inline]
   pub fn try_from(s: &str) -> Result<Self, ExceedsCapacity> {
     let length = s.len();
@@ -76,57 +145,351 @@ impl<const N: usize, Alignment> FixedStr<N, Alignment> {
       });
     }
 
-    let mut data = [0u8; N];
-    let bytes = s.as_bytes();
-    data[0..length].copy_from_slice(bytes);
-
-    Ok(FixedStr { data, align: [] })
+    Ok(FixedStr {
+      data: Self::pad(s.as_bytes()),
+      align: [],
+      padding: PhantomData,
+    })
   }
 
   /// Builds FixedStr within a const context
   pub const fn const_from(s: &str) -> Self {
-    let length = s.len();
-
-    if length > N {
+    if s.len() > N {
       panic!("String length exceeds capacity");
     }
 
-    let bytes = s.as_bytes();
-
-    let mut t = FixedStr {
-      data: [0; N],
+    FixedStr {
+      data: Self::pad(s.as_bytes()),
       align: [],
-    };
-
-    {
-      let (left, _) = t.data.split_at_mut(length);
-      left.copy_from_slice(bytes);
+      padding: PhantomData,
     }
-
-    t
   }
 
   /// Attempts to build FixedStr within a const context
   #[inline]
   pub const fn const_try_from(s: &str) -> Option<Self> {
-    let length = s.len();
-
-    if length > N {
+    if s.len() > N {
       None
     } else {
-      let bytes = s.as_bytes();
+      Some(FixedStr {
+        data: Self::pad(s.as_bytes()),
+        align: [],
+        padding: PhantomData,
+      })
+    }
+  }
+
+  /// Places `bytes` according to the padding policy and fills the remaining
+  /// space. Requires that `bytes.len() <= N`.
+  const fn pad(bytes: &[u8]) -> [u8; N] {
+    let mut data = [P::BYTE; N];
+
+    let (_, target) = if P::RIGHT_ALIGNED {
+      data.split_at_mut(N - bytes.len())
+    } else {
+      let (left, right) = data.split_at_mut(bytes.len());
+      (right, left)
+    };
+
+    target.copy_from_slice(bytes);
+    data
+  }
+
+  /// Checks that `data` is valid UTF-8
+  pub(crate) const fn validate(data: &[u8]) -> Result<(), InvalidBytes> {
+    match core::str::from_utf8(data) {
+      Ok(_) => Ok(()),
+      Err(e) => Err(InvalidBytes::InvalidUtf8 {
+        offset: e.valid_up_to(),
+      }),
+    }
+  }
+
+  /// Casts `bytes` to a FixedStr reference without copying
+  ///
+  /// `bytes` must match FixedStr's size and alignment. The content is
+  /// validated before the reference is returned.
+  #[cfg(feature = "zerocopy")]
+  pub fn try_ref_from_bytes(bytes: &[u8]) -> Result<&Self, InvalidBytes>
+  where
+    Alignment: zerocopy::FromBytes + zerocopy::KnownLayout + zerocopy::Immutable,
+  {
+    let raw = <RawFixedStr<N, Alignment> as zerocopy::FromBytes>::ref_from_bytes(bytes)
+      .map_err(|_| InvalidBytes::Layout)?;
+
+    Self::validate(&raw.data)?;
+
+    // SAFETY: RawFixedStr has the same layout as FixedStr, and the content was
+    //         checked above
+    Ok(unsafe { &*(raw as *const RawFixedStr<N, Alignment>).cast::<Self>() })
+  }
 
-      let mut t = FixedStr {
-        data: [0; N],
+  /// Casts `bytes` to a FixedStr slice without copying
+  ///
+  /// `bytes` must be aligned and its length must be a multiple of FixedStr's
+  /// size. Every item is validated before the slice is returned.
+  #[cfg(feature = "zerocopy")]
+  pub fn try_slice_from_bytes(bytes: &[u8]) -> Result<&[Self], InvalidBytes>
+  where
+    Alignment: zerocopy::FromBytes + zerocopy::KnownLayout + zerocopy::Immutable,
+  {
+    let raw = <[RawFixedStr<N, Alignment>] as zerocopy::FromBytes>::ref_from_bytes(bytes)
+      .map_err(|_| InvalidBytes::Layout)?;
+
+    for item in raw {
+      Self::validate(&item.data)?;
+    }
+
+    // SAFETY: RawFixedStr has the same layout as FixedStr, and the content of
+    //         every item was checked above
+    Ok(unsafe { core::slice::from_raw_parts(raw.as_ptr().cast::<Self>(), raw.len()) })
+  }
+
+  /// Encodes FixedStr in the wire format
+  ///
+  /// The wire format consists of the content and padding as laid out in
+  /// memory. `SIZE` must equal `N` and is usually inferred.
+  pub fn to_bytes<const SIZE: usize>(&self) -> [u8; SIZE] {
+    const { assert!(SIZE == N, "SIZE must equal N") };
+
+    let mut bytes = [0u8; SIZE];
+    bytes.copy_from_slice(&self.data);
+    bytes
+  }
+
+  /// Decodes FixedStr from the wire format
+  ///
+  /// Returns `Err` if the content is not valid UTF-8.
+  pub fn try_from_bytes<const SIZE: usize>(bytes: &[u8; SIZE]) -> Result<Self, InvalidBytes> {
+    const { assert!(SIZE == N, "SIZE must equal N") };
+
+    let mut data = [0u8; N];
+    data.copy_from_slice(bytes);
+
+    Self::validate(&data)?;
+
+    Ok(FixedStr {
+      data,
+      align: [],
+      padding: PhantomData,
+    })
+  }
+
+  /// Decodes FixedStr from `bytes` within a const context
+  ///
+  /// Like [Self::try_from_bytes], but `bytes` must be exactly `N` bytes long.
+  /// Returns `Err` with the offset of the first invalid byte if `bytes` is not
+  /// valid UTF-8.
+  pub const fn const_try_from_bytes(bytes: &[u8; N]) -> Result<Self, InvalidBytes> {
+    match Self::validate(bytes) {
+      Ok(()) => Ok(FixedStr {
+        data: *bytes,
         align: [],
-      };
+        padding: PhantomData,
+      }),
+      Err(e) => Err(e),
+    }
+  }
+
+  /// Constructs FixedStr from a byte slice of up to `N` bytes
+  ///
+  /// Shorter slices are padded. Returns `Err` if `bytes` exceeds the capacity
+  /// or is not valid UTF-8.
+  pub const fn try_from_slice(bytes: &[u8]) -> Result<Self, InvalidBytes> {
+    if bytes.len() > N {
+      return Err(InvalidBytes::ExceedsCapacity(ExceedsCapacity {
+        length: bytes.len(),
+        capacity: N,
+      }));
+    }
+
+    match Self::validate(bytes) {
+      Ok(()) => Ok(FixedStr {
+        data: Self::pad(bytes),
+        align: [],
+        padding: PhantomData,
+      }),
+      Err(e) => Err(e),
+    }
+  }
+
+  /// Constructs FixedStr from ASCII bytes
+  ///
+  /// Checking for ASCII is cheaper than validating UTF-8. Returns `Err` with
+  /// the offset of the first non-ASCII byte.
+  pub const fn try_from_ascii(bytes: &[u8; N]) -> Result<Self, InvalidBytes> {
+    match check_ascii(bytes) {
+      Ok(()) => Ok(FixedStr {
+        data: *bytes,
+        align: [],
+        padding: PhantomData,
+      }),
+      Err(e) => Err(e),
+    }
+  }
+
+  /// Constructs FixedStr from a slice of up to `N` ASCII bytes
+  ///
+  /// Shorter slices are padded. Returns `Err` if `bytes` exceeds the capacity
+  /// or contains non-ASCII bytes.
+  pub const fn try_from_ascii_slice(bytes: &[u8]) -> Result<Self, InvalidBytes> {
+    if bytes.len() > N {
+      return Err(InvalidBytes::ExceedsCapacity(ExceedsCapacity {
+        length: bytes.len(),
+        capacity: N,
+      }));
+    }
+
+    match check_ascii(bytes) {
+      Ok(()) => Ok(FixedStr {
+        data: Self::pad(bytes),
+        align: [],
+        padding: PhantomData,
+      }),
+      Err(e) => Err(e),
+    }
+  }
+
+  /// Encodes `bytes` as lowercase hexadecimal
+  ///
+  /// The encoding must fit into `N`, which is checked at compile time. Shorter
+  /// encodings are padded.
+  ///
+  /// ```rust
+  /// # use qstr::FStr8;
+  /// let s = FStr8::encode_hex(&[0xde, 0xad, 0xbe, 0xef]);
+  /// assert_eq!(s.as_str(), "deadbeef");
+  /// assert_eq!(s.decode_hex(), Ok([0xde, 0xad, 0xbe, 0xef]));
+  /// ```
+  ///
+  /// Encodings exceeding the capacity are rejected at compile time:
+  /// ```compile_fail
+  /// # use qstr::FStr8;
+  /// let s = FStr8::encode_hex(&[0u8; 5]);
+  /// ```
+  pub fn encode_hex<const M: usize>(bytes: &[u8; M]) -> Self {
+    const { assert!(codec::HEX.encoded_len(M) <= N, "Encoding exceeds capacity") };
+    Self::encode(&codec::HEX, bytes)
+  }
+
+  /// Decodes hexadecimal content without padding. Accepts both lowercase and
+  /// uppercase digits.
+  pub fn decode_hex<const M: usize>(&self) -> Result<[u8; M], DecodeError> {
+    const { assert!(codec::HEX.encoded_len(M) <= N, "Encoding exceeds capacity") };
+    self.decode(&codec::HEX)
+  }
+
+  /// Encodes `bytes` as base32 (RFC 4648) with `=` padding
+  ///
+  /// The encoding must fit into `N`, which is checked at compile time.
+  pub fn encode_base32<const M: usize>(bytes: &[u8; M]) -> Self {
+    const {
+      assert!(
+        codec::BASE32.encoded_len(M) <= N,
+        "Encoding exceeds capacity"
+      )
+    };
+    Self::encode(&codec::BASE32, bytes)
+  }
+
+  /// Decodes base32 content (RFC 4648) with `=` padding
+  pub fn decode_base32<const M: usize>(&self) -> Result<[u8; M], DecodeError> {
+    const {
+      assert!(
+        codec::BASE32.encoded_len(M) <= N,
+        "Encoding exceeds capacity"
+      )
+    };
+    self.decode(&codec::BASE32)
+  }
+
+  /// Encodes `bytes` as standard base64 (RFC 4648) with `=` padding
+  ///
+  /// The encoding must fit into `N`, which is checked at compile time.
+  pub fn encode_base64<const M: usize>(bytes: &[u8; M]) -> Self {
+    const {
+      assert!(
+        codec::BASE64.encoded_len(M) <= N,
+        "Encoding exceeds capacity"
+      )
+    };
+    Self::encode(&codec::BASE64, bytes)
+  }
+
+  /// Decodes standard base64 content (RFC 4648) with `=` padding
+  pub fn decode_base64<const M: usize>(&self) -> Result<[u8; M], DecodeError> {
+    const {
+      assert!(
+        codec::BASE64.encoded_len(M) <= N,
+        "Encoding exceeds capacity"
+      )
+    };
+    self.decode(&codec::BASE64)
+  }
+
+  /// Encodes `bytes` as URL-safe base64 (RFC 4648) without padding
+  ///
+  /// The encoding must fit into `N`, which is checked at compile time.
+  pub fn encode_base64_url<const M: usize>(bytes: &[u8; M]) -> Self {
+    const {
+      assert!(
+        codec::BASE64_URL.encoded_len(M) <= N,
+        "Encoding exceeds capacity"
+      )
+    };
+    Self::encode(&codec::BASE64_URL, bytes)
+  }
+
+  /// Decodes URL-safe base64 content (RFC 4648) without padding
+  pub fn decode_base64_url<const M: usize>(&self) -> Result<[u8; M], DecodeError> {
+    const {
+      assert!(
+        codec::BASE64_URL.encoded_len(M) <= N,
+        "Encoding exceeds capacity"
+      )
+    };
+    self.decode(&codec::BASE64_URL)
+  }
+
+  fn encode(alphabet: &Alphabet, bytes: &[u8]) -> Self {
+    let mut buf = [0u8; N];
+    let length = alphabet.encoded_len(bytes.len());
+    alphabet.encode(bytes, &mut buf[..length]);
+
+    FixedStr {
+      data: Self::pad(&buf[..length]),
+      align: [],
+      padding: PhantomData,
+    }
+  }
+
+  /// Decodes the `encoded_len(M)` bytes on the content side. Offsets in errors
+  /// are relative to these bytes.
+  ///
+  /// The padding byte may be part of the encoding, e.g. the leading zeros of
+  /// right-aligned hex digits, so the padding is not trimmed beforehand. If
+  /// the content without padding has a different length and the bytes fail to
+  /// decode, its length is reported instead.
+  fn decode<const M: usize>(&self, alphabet: &Alphabet) -> Result<[u8; M], DecodeError> {
+    let expected = alphabet.encoded_len(M);
+    let length = self.content_range().len();
+
+    if length > expected {
+      return Err(DecodeError::InvalidLength { length, expected });
+    }
+
+    let range = if P::RIGHT_ALIGNED {
+      N - expected..N
+    } else {
+      0..expected
+    };
 
-      {
-        let (left, _) = t.data.split_at_mut(length);
-        left.copy_from_slice(bytes);
-      }
+    let mut out = [0u8; M];
 
-      Some(t)
+    match alphabet.decode(&self.data[range], &mut out) {
+      Ok(()) => Ok(out),
+      Err(_) if length != expected => Err(DecodeError::InvalidLength { length, expected }),
+      Err(e) => Err(e),
     }
   }
 
@@ -136,12 +499,27 @@ impl<const N: usize, Alignment> FixedStr<N, Alignment> {
     &self.data
   }
 
+  /// Returns the underlying byte buffer as a [bstr::BStr] for use with
+  /// [bstr::ByteSlice]. Like [Self::as_bytes], it includes any NUL padding.
+  #[cfg(feature = "bstr")]
+  #[inline]
+  pub fn as_bstr(&self) -> &bstr::BStr {
+    bstr::BStr::new(&self.data)
+  }
+
   /// Converts FixedStr to `&str`
   #[inline]
   pub fn as_str(&self) -> &str {
     unsafe { core::str::from_utf8_unchecked(&self.data) }
   }
 
+  /// Converts FixedStr to `&mut str`, which only permits changes that keep
+  /// the content valid UTF-8
+  #[inline]
+  pub(crate) fn as_mut_str(&mut self) -> &mut str {
+    unsafe { core::str::from_utf8_unchecked_mut(&mut self.data) }
+  }
+
   /// Converts FixedStr to a &str. If the value is NUL-padded or contains NULs,
   /// this stops at the first NUL byte.
   #[inline]
@@ -155,6 +533,167 @@ impl<const N: usize, Alignment> FixedStr<N, Alignment> {
     unsafe { core::str::from_utf8_unchecked(&self.data[..length]) }
   }
 
+  /// Converts FixedStr to `&str` without the padding
+  ///
+  /// Unlike [Self::as_str_trimmed], NULs within the content are retained.
+  /// Content that starts or ends with the padding byte on the padded side
+  /// cannot be told apart from padding and is trimmed as well.
+  #[inline]
+  pub fn trimmed(&self) -> &str {
+    // SAFETY: The padding byte is ASCII, so a UTF-8 string remains valid
+    //         after removing it from either end
+    unsafe { core::str::from_utf8_unchecked(&self.data[self.content_range()]) }
+  }
+
+  /// Range of the content without the padding
+  #[inline]
+  fn content_range(&self) -> Range<usize> {
+    if P::RIGHT_ALIGNED {
+      let start = self.data.iter().position(|&b| b != P::BYTE).unwrap_or(N);
+
+      start..N
+    } else {
+      let end = self
+        .data
+        .iter()
+        .rposition(|&b| b != P::BYTE)
+        .map_or(0, |i| i + 1);
+
+      0..end
+    }
+  }
+
+  /// Returns a copy without leading and trailing whitespace in the content
+  ///
+  /// Whitespace is defined as in [str::trim]. The result is padded again.
+  #[inline]
+  pub fn trim(&self) -> Self {
+    self.with_content(str::trim)
+  }
+
+  /// Returns a copy without leading whitespace in the content
+  #[inline]
+  pub fn trim_start(&self) -> Self {
+    self.with_content(str::trim_start)
+  }
+
+  /// Returns a copy without trailing whitespace in the content
+  #[inline]
+  pub fn trim_end(&self) -> Self {
+    self.with_content(str::trim_end)
+  }
+
+  /// Constructs a FixedStr from a substring of the content
+  fn with_content(&self, f: impl FnOnce(&str) -> &str) -> Self {
+    FixedStr {
+      data: Self::pad(f(self.trimmed()).as_bytes()),
+      align: [],
+      padding: PhantomData,
+    }
+  }
+
+  /// Returns a copy with ASCII letters in the content converted to
+  /// lowercase, leaving the padding unchanged
+  #[inline]
+  pub fn to_ascii_lowercase(&self) -> Self {
+    let mut data = self.data;
+    data[self.content_range()].make_ascii_lowercase();
+
+    FixedStr {
+      data,
+      align: [],
+      padding: PhantomData,
+    }
+  }
+
+  /// Returns a copy with ASCII letters in the content converted to
+  /// uppercase, leaving the padding unchanged
+  #[inline]
+  pub fn to_ascii_uppercase(&self) -> Self {
+    let mut data = self.data;
+    data[self.content_range()].make_ascii_uppercase();
+
+    FixedStr {
+      data,
+      align: [],
+      padding: PhantomData,
+    }
+  }
+
+  /// Replaces all occurrences of `from` with `to` in the content
+  ///
+  /// The capacity `M` of the result is usually inferred and may differ from
+  /// `N`. Returns `Err` if the result exceeds it.
+  ///
+  /// # Usage
+  /// ```rust
+  /// # use qstr::{Align8, FixedStr, Space};
+  /// type Field = FixedStr<8, Align8, Space>;
+  ///
+  /// let s = Field::from("2024/01");
+  /// let r: Field = s.replace("/", "-").unwrap();
+  /// assert_eq!(r.as_str(), "2024-01 ");
+  /// ```
+  pub fn replace<const M: usize, B>(
+    &self,
+    from: &str,
+    to: &str,
+  ) -> Result<FixedStr<M, B, P>, ExceedsCapacity> {
+    let mut buf = [0u8; M];
+    let length = transform::replace_into(self.trimmed(), from, to, &mut buf)?;
+
+    Ok(FixedStr {
+      data: FixedStr::<M, B, P>::pad(&buf[..length]),
+      align: [],
+      padding: PhantomData,
+    })
+  }
+
+  /// Repeats the content `n` times
+  ///
+  /// The capacity `M` of the result is usually inferred and may differ from
+  /// `N`. Returns `Err` if the result exceeds it.
+  pub fn repeat<const M: usize, B>(&self, n: usize) -> Result<FixedStr<M, B, P>, ExceedsCapacity> {
+    let mut buf = [0u8; M];
+    let length = transform::repeat_into(self.trimmed(), n, &mut buf)?;
+
+    Ok(FixedStr {
+      data: FixedStr::<M, B, P>::pad(&buf[..length]),
+      align: [],
+      padding: PhantomData,
+    })
+  }
+
+  /// Checks if the content without the padding equals `other`, ignoring
+  /// ASCII case
+  ///
+  /// Compares eight bytes at a time.
+  #[inline]
+  pub fn eq_ignore_ascii_case(&self, other: &str) -> bool {
+    swar::eq_ignore_ascii_case(self.trimmed().as_bytes(), other.as_bytes())
+  }
+
+  /// Compares the content without the padding with `other`
+  /// lexicographically, ignoring ASCII case
+  ///
+  /// Compares eight bytes at a time.
+  #[inline]
+  pub fn cmp_ignore_ascii_case(&self, other: &str) -> Ordering {
+    swar::cmp_ignore_ascii_case(self.trimmed().as_bytes(), other.as_bytes())
+  }
+
+  /// Returns the length of the content without the padding in bytes
+  #[inline]
+  pub fn len(&self) -> usize {
+    self.trimmed().len()
+  }
+
+  /// Checks if FixedStr consists of padding only
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
   // Note: to_string() is provided via fmt::Display
 
   /// Converts FixedStr to a String. If the value is NUL-padded or contains
@@ -165,25 +704,91 @@ impl<const N: usize, Alignment> FixedStr<N, Alignment> {
   }
 }
 
-impl<const N: usize, Alignment> Default for FixedStr<N, Alignment> {
+/// Checks that `bytes` is ASCII, locating the first offending byte only on
+/// failure
+pub(crate) const fn check_ascii(bytes: &[u8]) -> Result<(), InvalidBytes> {
+  if bytes.is_ascii() {
+    return Ok(());
+  }
+
+  let mut offset = 0;
+
+  while bytes[offset].is_ascii() {
+    offset += 1;
+  }
+
+  Err(InvalidBytes::NonAscii { offset })
+}
+
+impl<const N: usize, Alignment, P: Padding> Default for FixedStr<N, Alignment, P> {
   fn default() -> Self {
     FixedStr::new()
   }
 }
 
-impl<const N: usize, Alignment> fmt::Display for FixedStr<N, Alignment> {
+/// Compares the data buffer a word at a time
+impl<const N: usize, Alignment, P> PartialEq for FixedStr<N, Alignment, P> {
+  #[inline]
+  fn eq(&self, other: &Self) -> bool {
+    swar::eq_words(&self.data, &other.data)
+  }
+}
+
+impl<const N: usize, Alignment, P> Eq for FixedStr<N, Alignment, P> {}
+
+impl<const N: usize, Alignment, P> PartialOrd for FixedStr<N, Alignment, P> {
+  #[inline]
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl<const N: usize, Alignment, P> Ord for FixedStr<N, Alignment, P> {
+  #[inline]
+  fn cmp(&self, other: &Self) -> Ordering {
+    swar::cmp_words(&self.data, &other.data)
+  }
+}
+
+impl<const N: usize, Alignment, P> Hash for FixedStr<N, Alignment, P> {
+  #[inline]
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    swar::hash_words(&self.data, state);
+  }
+}
+
+/// Writes the full content including padding, i.e. exactly `N` bytes
+impl<const N: usize, Alignment, P: Padding> fmt::Display for FixedStr<N, Alignment, P> {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     f.write_str(self.as_str())
   }
 }
 
-impl<const N: usize, Alignment> fmt::Debug for FixedStr<N, Alignment> {
+impl<const N: usize, Alignment, P: Padding> fmt::Debug for FixedStr<N, Alignment, P> {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-    f.write_str(self.as_str_trimmed())
+    f.write_str(self.trimmed())
+  }
+}
+
+/// Compares the content without the padding
+impl<const N: usize, Alignment, P: Padding> CaseInsensitive for FixedStr<N, Alignment, P> {
+  #[inline]
+  fn case_insensitive_eq(&self, other: &Self) -> bool {
+    swar::eq_ignore_ascii_case(self.trimmed().as_bytes(), other.trimmed().as_bytes())
+  }
+
+  #[inline]
+  fn case_insensitive_cmp(&self, other: &Self) -> Ordering {
+    swar::cmp_ignore_ascii_case(self.trimmed().as_bytes(), other.trimmed().as_bytes())
+  }
+
+  #[inline]
+  fn case_insensitive_hash<H: Hasher>(&self, state: &mut H) {
+    swar::hash_ignore_ascii_case(self.trimmed().as_bytes(), state);
   }
 }
 
-impl<const N: usize, Alignment> From<&str> for FixedStr<N, Alignment> {
+impl<const N: usize, Alignment, P: Padding> From<&str> for FixedStr<N, Alignment, P> {
   #[track_caller]
   fn from(s: &str) -> Self {
     Self::try_from(s).unwrap()
@@ -191,7 +796,7 @@ impl<const N: usize, Alignment> From<&str> for FixedStr<N, Alignment> {
 }
 
 #[cfg(feature = "std")]
-impl<const N: usize, Alignment> From<&String> for FixedStr<N, Alignment> {
+impl<const N: usize, Alignment, P: Padding> From<&String> for FixedStr<N, Alignment, P> {
   #[track_caller]
   fn from(s: &String) -> Self {
     Self::try_from(s).unwrap()
@@ -199,7 +804,7 @@ impl<const N: usize, Alignment> From<&String> for FixedStr<N, Alignment> {
 }
 
 #[cfg(feature = "std")]
-impl<const N: usize, Alignment> From<String> for FixedStr<N, Alignment> {
+impl<const N: usize, Alignment, P: Padding> From<String> for FixedStr<N, Alignment, P> {
   #[track_caller]
   fn from(s: String) -> Self {
     Self::try_from(&s).unwrap()
@@ -207,7 +812,7 @@ impl<const N: usize, Alignment> From<String> for FixedStr<N, Alignment> {
 }
 
 #[cfg(feature = "serde")]
-impl<const N: usize, Alignment> Serialize for FixedStr<N, Alignment> {
+impl<const N: usize, Alignment, P: Padding> Serialize for FixedStr<N, Alignment, P> {
   fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
   where
     S: serde::Serializer,
@@ -217,7 +822,7 @@ impl<const N: usize, Alignment> Serialize for FixedStr<N, Alignment> {
 }
 
 #[cfg(feature = "serde")]
-impl<'de, const N: usize, Alignment> Deserialize<'de> for FixedStr<N, Alignment> {
+impl<'de, const N: usize, Alignment, P: Padding> Deserialize<'de> for FixedStr<N, Alignment, P> {
   fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
   where
     D: serde::Deserializer<'de>,
@@ -226,3 +831,522 @@ impl<'de, const N: usize, Alignment> Deserialize<'de> for FixedStr<N, Alignment>
     FixedStr::try_from(&v).map_err(serde::de::Error::custom)
   }
 }
+
+// SAFETY: FixedStr is `repr(C)` and only consists of bytes. Its alignment is
+//         fixed by the `Alignment` marker, which must be `Portable` itself.
+#[cfg(feature = "rkyv")]
+unsafe impl<const N: usize, Alignment: Portable, P: Padding> Portable
+  for FixedStr<N, Alignment, P>
+{
+}
+
+/// The archived form of a FixedStr is identical to its in-memory layout and
+/// can be accessed without copying
+#[cfg(feature = "rkyv")]
+impl<const N: usize, Alignment: Portable, P: Padding> Archive for FixedStr<N, Alignment, P> {
+  type Archived = Self;
+  type Resolver = ();
+
+  fn resolve(&self, _: Self::Resolver, out: Place<Self::Archived>) {
+    munge!(let FixedStr { data, .. } = out);
+    data.write(self.data);
+  }
+}
+
+#[cfg(feature = "rkyv")]
+impl<S, const N: usize, Alignment, P: Padding> rkyv::Serialize<S> for FixedStr<N, Alignment, P>
+where
+  S: Fallible + ?Sized,
+  Alignment: Portable,
+{
+  fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+    Ok(())
+  }
+}
+
+#[cfg(feature = "rkyv")]
+impl<D, const N: usize, Alignment, P: Padding> rkyv::Deserialize<Self, D>
+  for FixedStr<N, Alignment, P>
+where
+  D: Fallible + ?Sized,
+{
+  fn deserialize(&self, _: &mut D) -> Result<Self, D::Error> {
+    Ok(FixedStr {
+      data: self.data,
+      align: [],
+      padding: PhantomData,
+    })
+  }
+}
+
+// SAFETY: Every byte pattern is a valid data buffer. UTF-8 validity is checked
+//         by validate().
+#[cfg(feature = "rkyv")]
+unsafe impl<C, const N: usize, Alignment, P: Padding> CheckBytes<C> for FixedStr<N, Alignment, P>
+where
+  C: Fallible + ?Sized,
+  C::Error: rkyv::rancor::Source,
+{
+  unsafe fn check_bytes(value: *const Self, _: &mut C) -> Result<(), C::Error> {
+    let value = unsafe { &*value };
+    Self::validate(&value.data).map_err(rkyv::rancor::Source::new)
+  }
+}
+
+// SAFETY: An all-zero FixedStr consists of NUL characters
+#[cfg(feature = "bytemuck")]
+unsafe impl<const N: usize, Alignment, P: Padding> bytemuck::Zeroable
+  for FixedStr<N, Alignment, P>
+{
+}
+
+// SAFETY: RawFixedStr has the same layout as FixedStr. validate() checks UTF-8
+//         validity.
+#[cfg(feature = "bytemuck")]
+unsafe impl<const N: usize, Alignment: Copy + 'static, P: Padding + Copy + 'static>
+  bytemuck::CheckedBitPattern for FixedStr<N, Alignment, P>
+{
+  type Bits = RawFixedStr<N, Alignment>;
+
+  fn is_valid_bit_pattern(bits: &Self::Bits) -> bool {
+    Self::validate(&bits.data).is_ok()
+  }
+}
+
+/// Encodes the full content including padding, like `as_str()`
+#[cfg(feature = "borsh")]
+impl<const N: usize, Alignment, P: Padding> borsh::BorshSerialize for FixedStr<N, Alignment, P> {
+  fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+    borsh::BorshSerialize::serialize(self.as_str(), writer)
+  }
+}
+
+/// Decodes a borsh string directly into the stack buffer. Shorter strings are
+/// padded according to `P`.
+#[cfg(feature = "borsh")]
+impl<const N: usize, Alignment, P: Padding> borsh::BorshDeserialize for FixedStr<N, Alignment, P> {
+  fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+    use borsh::io::{Error, ErrorKind};
+
+    let length = <u32 as borsh::BorshDeserialize>::deserialize_reader(reader)? as usize;
+
+    if length > N {
+      return Err(Error::new(
+        ErrorKind::InvalidData,
+        "String length exceeds capacity",
+      ));
+    }
+
+    let mut buf = [0u8; N];
+    reader.read_exact(&mut buf[..length])?;
+
+    if core::str::from_utf8(&buf[..length]).is_err() {
+      return Err(Error::new(ErrorKind::InvalidData, "Invalid UTF-8"));
+    }
+
+    Ok(FixedStr {
+      data: Self::pad(&buf[..length]),
+      align: [],
+      padding: PhantomData,
+    })
+  }
+}
+
+/// Encodes the full content including padding, like `as_str()`
+#[cfg(feature = "bincode")]
+impl<const N: usize, Alignment, P: Padding> bincode::Encode for FixedStr<N, Alignment, P> {
+  fn encode<E: bincode::enc::Encoder>(
+    &self,
+    encoder: &mut E,
+  ) -> Result<(), bincode::error::EncodeError> {
+    bincode::Encode::encode(self.as_str(), encoder)
+  }
+}
+
+/// Decodes a bincode string directly into the stack buffer. Shorter strings
+/// are padded according to `P`.
+#[cfg(feature = "bincode")]
+impl<Context, const N: usize, Alignment, P: Padding> bincode::Decode<Context>
+  for FixedStr<N, Alignment, P>
+{
+  fn decode<D: bincode::de::Decoder<Context = Context>>(
+    decoder: &mut D,
+  ) -> Result<Self, bincode::error::DecodeError> {
+    use bincode::de::read::Reader;
+    use bincode::error::DecodeError;
+
+    let length = <u64 as bincode::Decode<Context>>::decode(decoder)?;
+
+    if length > N as u64 {
+      return Err(DecodeError::Other("String length exceeds capacity"));
+    }
+
+    let length = length as usize;
+    decoder.claim_bytes_read(length)?;
+
+    let mut buf = [0u8; N];
+    decoder.reader().read(&mut buf[..length])?;
+
+    if let Err(inner) = core::str::from_utf8(&buf[..length]) {
+      return Err(DecodeError::Utf8 { inner });
+    }
+
+    Ok(FixedStr {
+      data: Self::pad(&buf[..length]),
+      align: [],
+      padding: PhantomData,
+    })
+  }
+}
+
+#[cfg(feature = "bincode")]
+impl<'de, Context, const N: usize, Alignment, P: Padding> bincode::BorrowDecode<'de, Context>
+  for FixedStr<N, Alignment, P>
+{
+  fn borrow_decode<D: bincode::de::BorrowDecoder<'de, Context = Context>>(
+    decoder: &mut D,
+  ) -> Result<Self, bincode::error::DecodeError> {
+    bincode::Decode::decode(decoder)
+  }
+}
+
+/// Length prefix followed by exactly `N` bytes
+#[cfg(feature = "postcard")]
+impl<const N: usize, Alignment, P: Padding> postcard::experimental::max_size::MaxSize
+  for FixedStr<N, Alignment, P>
+{
+  const POSTCARD_MAX_SIZE: usize = crate::varint_size(N) + N;
+}
+
+/// A string with `maxLength` set to `N`. With NUL padding, a pattern allows
+/// for the padding on either side. Other padding bytes may also occur within
+/// the content and are therefore not constrained.
+///
+/// Serialised values always span all `N` bytes. Shorter strings are accepted
+/// when deserialising and padded. Note that `N` limits the UTF-8 encoded
+/// length in bytes, whereas JSON Schema counts characters.
+#[cfg(feature = "schemars")]
+impl<const N: usize, Alignment, P: Padding> schemars::JsonSchema for FixedStr<N, Alignment, P> {
+  fn inline_schema() -> bool {
+    true
+  }
+
+  fn schema_name() -> Cow<'static, str> {
+    format!("FixedStr{N}").into()
+  }
+
+  fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+    let mut schema = schemars::json_schema!({
+      "type": "string",
+      "maxLength": N,
+    });
+
+    if P::BYTE == 0 {
+      let pattern = if P::RIGHT_ALIGNED {
+        "^\\u0000*[^\\u0000]*$"
+      } else {
+        "^[^\\u0000]*\\u0000*$"
+      };
+
+      schema.insert("pattern".into(), pattern.into());
+    }
+
+    schema
+  }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, const N: usize, Alignment, P: Padding> arbitrary::Arbitrary<'a>
+  for FixedStr<N, Alignment, P>
+{
+  fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+    let fill = crate::generate::arbitrary_fill(u)?;
+    let chars = crate::generate::arbitrary_chars(u, N)?;
+
+    Ok(Self::generate(chars, fill))
+  }
+}
+
+#[cfg(feature = "proptest")]
+impl<const N: usize, Alignment: 'static, P: Padding> proptest::arbitrary::Arbitrary
+  for FixedStr<N, Alignment, P>
+{
+  type Parameters = ();
+  type Strategy = proptest::strategy::BoxedStrategy<Self>;
+
+  fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+    use proptest::strategy::Strategy;
+
+    let chars = proptest::collection::vec(crate::generate::proptest_char(), 0..=N);
+
+    (chars, crate::generate::proptest_fill())
+      .prop_map(|(chars, fill)| Self::generate(chars, fill))
+      .boxed()
+  }
+}
+
+#[cfg(feature = "quickcheck")]
+impl<const N: usize, Alignment: Clone + 'static, P: Padding + Clone + 'static> quickcheck::Arbitrary
+  for FixedStr<N, Alignment, P>
+{
+  fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+    let fill = crate::generate::quickcheck_fill(g);
+    Self::generate(crate::generate::quickcheck_chars(g, N), fill)
+  }
+
+  fn shrink(&self) -> alloc::boxed::Box<dyn Iterator<Item = Self>> {
+    let s = self.trimmed();
+
+    alloc::boxed::Box::new(
+      quickcheck::Arbitrary::shrink(&alloc::string::String::from(s))
+        .map(|s| Self::generate(s.chars(), false)),
+    )
+  }
+}
+
+/// Prints the value without the padding
+#[cfg(feature = "defmt")]
+impl<const N: usize, Alignment, P: Padding> defmt::Format for FixedStr<N, Alignment, P> {
+  fn format(&self, f: defmt::Formatter) {
+    defmt::write!(f, "{=str}", self.trimmed())
+  }
+}
+
+/// Prints the value without the padding
+#[cfg(feature = "ufmt")]
+impl<const N: usize, Alignment, P: Padding> ufmt::uDisplay for FixedStr<N, Alignment, P> {
+  fn fmt<W: ufmt::uWrite + ?Sized>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error> {
+    f.write_str(self.trimmed())
+  }
+}
+
+/// Prints the value without the padding
+#[cfg(feature = "ufmt")]
+impl<const N: usize, Alignment, P: Padding> ufmt::uDebug for FixedStr<N, Alignment, P> {
+  fn fmt<W: ufmt::uWrite + ?Sized>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error> {
+    f.write_str(self.trimmed())
+  }
+}
+
+/// Fallible conversions between any capacities, in place of [TryFrom]
+///
+/// The [From] impls exist for every combination of capacities, so [TryFrom]
+/// impls would conflict with them.
+#[cfg(feature = "heapless")]
+impl<const N: usize, Alignment, P: Padding> FixedStr<N, Alignment, P> {
+  /// Creates a FixedStr from a `heapless::String` of any capacity
+  pub fn try_from_heapless<const M: usize>(
+    s: &heapless::String<M>,
+  ) -> Result<Self, ExceedsCapacity> {
+    Self::try_from(s.as_str())
+  }
+
+  /// Converts the content without padding into a `heapless::String` of any
+  /// capacity
+  pub fn try_into_heapless<const M: usize>(&self) -> Result<heapless::String<M>, ExceedsCapacity> {
+    let s = self.trimmed();
+
+    heapless::String::try_from(s).map_err(|_| ExceedsCapacity {
+      length: s.len(),
+      capacity: M,
+    })
+  }
+}
+
+/// Infallible conversion. Requires `M <= N`.
+///
+/// The impl exists for all capacities. Others are only rejected once the
+/// conversion is built, e.g. by `cargo build` but not `cargo check`. Use
+/// [FixedStr::try_from_heapless] for them instead.
+#[cfg(feature = "heapless")]
+impl<const N: usize, const M: usize, Alignment, P: Padding> From<heapless::String<M>>
+  for FixedStr<N, Alignment, P>
+{
+  fn from(s: heapless::String<M>) -> Self {
+    const { assert!(M <= N, "FixedStr capacity must not be below the source's") };
+    Self::try_from(s.as_str()).unwrap()
+  }
+}
+
+/// Infallible conversion of the value without the padding. Requires `N <= M`.
+///
+/// The impl exists for all capacities. Others are only rejected once the
+/// conversion is built, e.g. by `cargo build` but not `cargo check`. Use
+/// [FixedStr::try_into_heapless] for them instead.
+#[cfg(feature = "heapless")]
+impl<const N: usize, const M: usize, Alignment, P: Padding> From<FixedStr<N, Alignment, P>>
+  for heapless::String<M>
+{
+  fn from(s: FixedStr<N, Alignment, P>) -> Self {
+    const { assert!(N <= M, "Target capacity must not be below FixedStr's") };
+
+    let mut result = Self::new();
+    result.push_str(s.trimmed()).unwrap();
+    result
+  }
+}
+
+/// Fallible conversions between any capacities, in place of [TryFrom]
+///
+/// The [From] impls exist for every combination of capacities, so [TryFrom]
+/// impls would conflict with them.
+#[cfg(feature = "arrayvec")]
+impl<const N: usize, Alignment, P: Padding> FixedStr<N, Alignment, P> {
+  /// Creates a FixedStr from an `arrayvec::ArrayString` of any capacity
+  pub fn try_from_arrayvec<const M: usize>(
+    s: &arrayvec::ArrayString<M>,
+  ) -> Result<Self, ExceedsCapacity> {
+    Self::try_from(s.as_str())
+  }
+
+  /// Converts the content without padding into an `arrayvec::ArrayString` of
+  /// any capacity
+  pub fn try_into_arrayvec<const M: usize>(
+    &self,
+  ) -> Result<arrayvec::ArrayString<M>, ExceedsCapacity> {
+    let s = self.trimmed();
+
+    arrayvec::ArrayString::from(s).map_err(|_| ExceedsCapacity {
+      length: s.len(),
+      capacity: M,
+    })
+  }
+}
+
+/// Infallible conversion. Requires `M <= N`.
+///
+/// The impl exists for all capacities. Others are only rejected once the
+/// conversion is built, e.g. by `cargo build` but not `cargo check`. Use
+/// [FixedStr::try_from_arrayvec] for them instead.
+#[cfg(feature = "arrayvec")]
+impl<const N: usize, const M: usize, Alignment, P: Padding> From<arrayvec::ArrayString<M>>
+  for FixedStr<N, Alignment, P>
+{
+  fn from(s: arrayvec::ArrayString<M>) -> Self {
+    const { assert!(M <= N, "FixedStr capacity must not be below the source's") };
+    Self::try_from(s.as_str()).unwrap()
+  }
+}
+
+/// Infallible conversion of the value without the padding. Requires `N <= M`.
+///
+/// The impl exists for all capacities. Others are only rejected once the
+/// conversion is built, e.g. by `cargo build` but not `cargo check`. Use
+/// [FixedStr::try_into_arrayvec] for them instead.
+#[cfg(feature = "arrayvec")]
+impl<const N: usize, const M: usize, Alignment, P: Padding> From<FixedStr<N, Alignment, P>>
+  for arrayvec::ArrayString<M>
+{
+  fn from(s: FixedStr<N, Alignment, P>) -> Self {
+    const { assert!(N <= M, "Target capacity must not be below FixedStr's") };
+
+    let mut result = Self::new();
+    result.push_str(s.trimmed());
+    result
+  }
+}
+
+/// Converts the value without the padding. Strings of up to 23 bytes are stored inline
+/// without allocating.
+#[cfg(feature = "smol_str")]
+impl<const N: usize, Alignment, P: Padding> From<FixedStr<N, Alignment, P>> for smol_str::SmolStr {
+  fn from(s: FixedStr<N, Alignment, P>) -> Self {
+    smol_str::SmolStr::new(s.trimmed())
+  }
+}
+
+#[cfg(feature = "smol_str")]
+impl<const N: usize, Alignment, P: Padding> TryFrom<&smol_str::SmolStr>
+  for FixedStr<N, Alignment, P>
+{
+  type Error = ExceedsCapacity;
+
+  fn try_from(s: &smol_str::SmolStr) -> Result<Self, Self::Error> {
+    Self::try_from(s.as_str())
+  }
+}
+
+/// Compares the value without the padding
+#[cfg(feature = "smol_str")]
+impl<const N: usize, Alignment, P: Padding> PartialEq<smol_str::SmolStr>
+  for FixedStr<N, Alignment, P>
+{
+  fn eq(&self, other: &smol_str::SmolStr) -> bool {
+    self.trimmed() == other.as_str()
+  }
+}
+
+/// Compares the value without the padding
+#[cfg(feature = "smol_str")]
+impl<const N: usize, Alignment, P: Padding> PartialEq<FixedStr<N, Alignment, P>>
+  for smol_str::SmolStr
+{
+  fn eq(&self, other: &FixedStr<N, Alignment, P>) -> bool {
+    self.as_str() == other.trimmed()
+  }
+}
+
+/// Converts the value without the padding. Strings of up to 24 bytes are stored inline
+/// without allocating.
+#[cfg(feature = "compact_str")]
+impl<const N: usize, Alignment, P: Padding> From<FixedStr<N, Alignment, P>>
+  for compact_str::CompactString
+{
+  fn from(s: FixedStr<N, Alignment, P>) -> Self {
+    compact_str::CompactString::new(s.trimmed())
+  }
+}
+
+#[cfg(feature = "compact_str")]
+impl<const N: usize, Alignment, P: Padding> TryFrom<&compact_str::CompactString>
+  for FixedStr<N, Alignment, P>
+{
+  type Error = ExceedsCapacity;
+
+  fn try_from(s: &compact_str::CompactString) -> Result<Self, Self::Error> {
+    Self::try_from(s.as_str())
+  }
+}
+
+/// Compares the value without the padding
+#[cfg(feature = "compact_str")]
+impl<const N: usize, Alignment, P: Padding> PartialEq<compact_str::CompactString>
+  for FixedStr<N, Alignment, P>
+{
+  fn eq(&self, other: &compact_str::CompactString) -> bool {
+    self.trimmed() == other.as_str()
+  }
+}
+
+/// Compares the value without the padding
+#[cfg(feature = "compact_str")]
+impl<const N: usize, Alignment, P: Padding> PartialEq<FixedStr<N, Alignment, P>>
+  for compact_str::CompactString
+{
+  fn eq(&self, other: &FixedStr<N, Alignment, P>) -> bool {
+    self.as_str() == other.trimmed()
+  }
+}
+
+#[cfg(feature = "bstr")]
+impl<const N: usize, Alignment, P: Padding> AsRef<bstr::BStr> for FixedStr<N, Alignment, P> {
+  fn as_ref(&self) -> &bstr::BStr {
+    self.as_bstr()
+  }
+}
+
+/// Compares the value without the padding
+#[cfg(feature = "bstr")]
+impl<const N: usize, Alignment, P: Padding> PartialEq<bstr::BStr> for FixedStr<N, Alignment, P> {
+  fn eq(&self, other: &bstr::BStr) -> bool {
+    other == self.trimmed()
+  }
+}
+
+/// Compares the value without the padding
+#[cfg(feature = "bstr")]
+impl<const N: usize, Alignment, P: Padding> PartialEq<FixedStr<N, Alignment, P>> for bstr::BStr {
+  fn eq(&self, other: &FixedStr<N, Alignment, P>) -> bool {
+    self == other.trimmed()
+  }
+}
@@ -0,0 +1,131 @@
+//! Helpers for generating arbitrary values, shared by the `arbitrary`,
+//! `proptest` and `quickcheck` integrations
+//!
+//! Generated strings are biased towards edge cases. Characters are drawn from
+//! [EDGE_CHARS] half of the time, and a quarter of the values are filled up to
+//! their full capacity with [pad].
+
+#[cfg(feature = "arbitrary")]
+use arbitrary::Unstructured;
+
+#[cfg(feature = "proptest")]
+use proptest::strategy::Strategy;
+
+/// Characters covering every UTF-8 encoded width, including NUL
+pub(crate) const EDGE_CHARS: [char; 6] = ['\0', 'a', '\u{7f}', 'ß', '€', '\u{1F600}'];
+
+/// Maximum number of characters per StrVec item
+pub(crate) const MAX_ITEM_CHARS: usize = 8;
+
+/// Writes as many characters to `buf` as fit and returns the number of bytes
+/// written
+pub(crate) fn write_chars(buf: &mut [u8], chars: impl IntoIterator<Item = char>) -> usize {
+  let mut offset = 0;
+
+  for c in chars {
+    if offset + c.len_utf8() > buf.len() {
+      break;
+    }
+
+    offset += c.encode_utf8(&mut buf[offset..]).len();
+  }
+
+  offset
+}
+
+/// Pads the string `buf[start..end]` with ASCII characters until it fills
+/// `buf`
+///
+/// The string's last character is moved to the end of `buf`, so that
+/// multi-byte characters end exactly at the capacity boundary.
+pub(crate) fn pad(buf: &mut [u8], start: usize, end: usize) {
+  let last = (start..end)
+    .rev()
+    .find(|&i| !(0x80..0xc0).contains(&buf[i]))
+    .unwrap_or(end);
+
+  let offset = buf.len() - (end - last);
+
+  buf.copy_within(last..end, offset);
+  buf[last..offset].fill(b'a');
+}
+
+/// Generates a character biased towards [EDGE_CHARS]
+#[cfg(feature = "arbitrary")]
+pub(crate) fn arbitrary_char(u: &mut Unstructured) -> arbitrary::Result<char> {
+  if u.ratio(1, 2)? {
+    u.choose(&EDGE_CHARS).copied()
+  } else {
+    u.arbitrary()
+  }
+}
+
+/// Decides whether an arbitrary value should be filled to its full capacity.
+/// Exhausted input yields `false`.
+#[cfg(feature = "arbitrary")]
+pub(crate) fn arbitrary_fill(u: &mut Unstructured) -> arbitrary::Result<bool> {
+  Ok(u.int_in_range(0..=3u8)? == 3)
+}
+
+/// Generates up to `max` characters, stopping early once `u` is exhausted
+#[cfg(feature = "arbitrary")]
+pub(crate) fn arbitrary_chars<'a>(
+  u: &mut Unstructured<'a>,
+  max: usize,
+) -> arbitrary::Result<impl Iterator<Item = char>> {
+  let count = u.int_in_range(0..=max)?;
+  Ok((0..count).map_while(|_| arbitrary_char(u).ok()))
+}
+
+/// Generates a character biased towards [EDGE_CHARS]
+#[cfg(feature = "quickcheck")]
+pub(crate) fn quickcheck_char(g: &mut quickcheck::Gen) -> char {
+  use quickcheck::Arbitrary;
+
+  if bool::arbitrary(g) {
+    *g.choose(&EDGE_CHARS).unwrap()
+  } else {
+    char::arbitrary(g)
+  }
+}
+
+/// Generates a count in `0..=max`
+#[cfg(feature = "quickcheck")]
+pub(crate) fn quickcheck_count(g: &mut quickcheck::Gen, max: usize) -> usize {
+  use quickcheck::Arbitrary;
+
+  usize::arbitrary(g) % (max + 1)
+}
+
+/// Generates up to `max` characters
+#[cfg(feature = "quickcheck")]
+pub(crate) fn quickcheck_chars(
+  g: &mut quickcheck::Gen,
+  max: usize,
+) -> impl Iterator<Item = char> + '_ {
+  let count = quickcheck_count(g, max);
+  (0..count).map(|_| quickcheck_char(g))
+}
+
+/// Decides whether a quickcheck value should be filled to its full capacity
+#[cfg(feature = "quickcheck")]
+pub(crate) fn quickcheck_fill(g: &mut quickcheck::Gen) -> bool {
+  use quickcheck::Arbitrary;
+
+  u8::arbitrary(g) % 4 == 0
+}
+
+/// Strategy for characters biased towards [EDGE_CHARS]
+#[cfg(feature = "proptest")]
+pub(crate) fn proptest_char() -> impl Strategy<Value = char> {
+  proptest::prop_oneof![
+    proptest::sample::select(&EDGE_CHARS[..]),
+    proptest::char::any(),
+  ]
+}
+
+/// Strategy for deciding whether a value should be filled to its full capacity
+#[cfg(feature = "proptest")]
+pub(crate) fn proptest_fill() -> impl Strategy<Value = bool> {
+  proptest::bool::weighted(0.25)
+}
@@ -0,0 +1,160 @@
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+
+#[cfg(feature = "serde")]
+use std::string::String;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::Align8;
+use crate::DecodeError;
+use crate::FixedStr;
+use crate::codec::{hex_digit, hex_value};
+
+/// Validated digest of `N` lowercase hexadecimal characters, encoding `N / 2`
+/// bytes
+///
+/// For example, a SHA-256 digest is represented by `HexDigest<64>`. The
+/// lexicographic order matches the order of the digest's bytes.
+///
+/// # Usage
+/// ```rust
+/// # use qstr::HexDigest;
+/// let digest = HexDigest::<8>::from_bytes(&[0xde, 0xad, 0xbe, 0xef]);
+/// assert_eq!(digest.as_str(), "deadbeef");
+/// assert_eq!(HexDigest::<8>::parse("DEADBEEF"), Ok(digest));
+/// assert_eq!(digest.to_bytes(), [0xde, 0xad, 0xbe, 0xef]);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct HexDigest<const N: usize>(FixedStr<N, Align8>);
+
+impl<const N: usize> HexDigest<N> {
+  /// Parses `N` hexadecimal characters in either case and normalises them to
+  /// lowercase
+  ///
+  /// Returns `Err` with the offset of the first invalid character.
+  pub const fn parse(s: &str) -> Result<Self, DecodeError> {
+    const { assert!(N.is_multiple_of(2), "N must be even") };
+
+    let bytes = s.as_bytes();
+
+    if bytes.len() != N {
+      return Err(DecodeError::InvalidLength {
+        length: bytes.len(),
+        expected: N,
+      });
+    }
+
+    let mut data = [0u8; N];
+    let mut i = 0;
+
+    while i < N {
+      data[i] = match hex_value(bytes[i]) {
+        Some(v) => hex_digit(v),
+        None => return Err(DecodeError::InvalidCharacter { offset: i }),
+      };
+
+      i += 1;
+    }
+
+    // SAFETY: `data` only consists of ASCII hex digits
+    Ok(HexDigest(unsafe { FixedStr::from_bytes(data) }))
+  }
+
+  /// Encodes the digest's `M` bytes, where `N` must equal `2 * M`
+  pub fn from_bytes<const M: usize>(bytes: &[u8; M]) -> Self {
+    const { assert!(2 * M == N, "N must equal twice the number of bytes") };
+    HexDigest(FixedStr::encode_hex(bytes))
+  }
+
+  /// Returns the digest's `M` bytes, where `N` must equal `2 * M`
+  pub fn to_bytes<const M: usize>(&self) -> [u8; M] {
+    const { assert!(2 * M == N, "N must equal twice the number of bytes") };
+
+    let mut bytes = [0u8; M];
+
+    for (i, pair) in self.0.as_bytes().chunks_exact(2).enumerate() {
+      // The content was validated on construction
+      let high = hex_value(pair[0]).unwrap_or(0);
+      let low = hex_value(pair[1]).unwrap_or(0);
+      bytes[i] = (high << 4) | low;
+    }
+
+    bytes
+  }
+
+  /// Converts HexDigest to `&str`
+  #[inline]
+  pub fn as_str(&self) -> &str {
+    self.0.as_str()
+  }
+
+  /// Returns the underlying FixedStr
+  #[inline]
+  pub fn as_fixed_str(&self) -> &FixedStr<N, Align8> {
+    &self.0
+  }
+}
+
+impl<const N: usize> Hash for HexDigest<N> {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.as_str().hash(state);
+  }
+}
+
+impl<const N: usize> fmt::Display for HexDigest<N> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+impl<const N: usize> fmt::Debug for HexDigest<N> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+impl<const N: usize> FromStr for HexDigest<N> {
+  type Err = DecodeError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Self::parse(s)
+  }
+}
+
+impl<const N: usize> TryFrom<&str> for HexDigest<N> {
+  type Error = DecodeError;
+
+  fn try_from(s: &str) -> Result<Self, Self::Error> {
+    Self::parse(s)
+  }
+}
+
+impl<const N: usize> From<HexDigest<N>> for FixedStr<N, Align8> {
+  fn from(digest: HexDigest<N>) -> Self {
+    digest.0
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<const N: usize> Serialize for HexDigest<N> {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    self.as_str().serialize(serializer)
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> Deserialize<'de> for HexDigest<N> {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: serde::Deserializer<'de>,
+  {
+    let v = String::deserialize(deserializer)?;
+    HexDigest::parse(&v).map_err(serde::de::Error::custom)
+  }
+}
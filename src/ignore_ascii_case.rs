@@ -0,0 +1,154 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+#[cfg(feature = "std")]
+use std::string::String;
+
+use crate::swar;
+
+/// Values that can be compared and hashed while ignoring ASCII case
+///
+/// The string types also have inherent `eq_ignore_ascii_case` and
+/// `cmp_ignore_ascii_case` methods, which take a `&str` like their [str]
+/// counterparts. The trait methods compare two values of the same type.
+pub trait CaseInsensitive {
+  /// Checks if two values are equal, ignoring ASCII case
+  fn case_insensitive_eq(&self, other: &Self) -> bool;
+
+  /// Compares two values, ignoring ASCII case
+  fn case_insensitive_cmp(&self, other: &Self) -> Ordering;
+
+  /// Feeds the value to `state` such that values equal ignoring ASCII case
+  /// produce the same hash
+  fn case_insensitive_hash<H: Hasher>(&self, state: &mut H);
+}
+
+impl CaseInsensitive for str {
+  #[inline]
+  fn case_insensitive_eq(&self, other: &Self) -> bool {
+    swar::eq_ignore_ascii_case(self.as_bytes(), other.as_bytes())
+  }
+
+  #[inline]
+  fn case_insensitive_cmp(&self, other: &Self) -> Ordering {
+    swar::cmp_ignore_ascii_case(self.as_bytes(), other.as_bytes())
+  }
+
+  #[inline]
+  fn case_insensitive_hash<H: Hasher>(&self, state: &mut H) {
+    swar::hash_ignore_ascii_case(self.as_bytes(), state);
+  }
+}
+
+#[cfg(feature = "std")]
+impl CaseInsensitive for String {
+  #[inline]
+  fn case_insensitive_eq(&self, other: &Self) -> bool {
+    CaseInsensitive::case_insensitive_eq(self.as_str(), other)
+  }
+
+  #[inline]
+  fn case_insensitive_cmp(&self, other: &Self) -> Ordering {
+    CaseInsensitive::case_insensitive_cmp(self.as_str(), other)
+  }
+
+  #[inline]
+  fn case_insensitive_hash<H: Hasher>(&self, state: &mut H) {
+    self.as_str().case_insensitive_hash(state);
+  }
+}
+
+impl<T: CaseInsensitive + ?Sized> CaseInsensitive for &T {
+  #[inline]
+  fn case_insensitive_eq(&self, other: &Self) -> bool {
+    T::case_insensitive_eq(self, other)
+  }
+
+  #[inline]
+  fn case_insensitive_cmp(&self, other: &Self) -> Ordering {
+    T::case_insensitive_cmp(self, other)
+  }
+
+  #[inline]
+  fn case_insensitive_hash<H: Hasher>(&self, state: &mut H) {
+    T::case_insensitive_hash(self, state);
+  }
+}
+
+/// Wrapper whose `Eq`, `Ord` and `Hash` implementations ignore ASCII case
+///
+/// Allows using strings as case-insensitive keys in maps and sets, e.g. for
+/// header names or hostnames, while retaining their original spelling. Strings
+/// are ordered lexicographically as if they were lowercase. Comparisons and
+/// hashing process eight bytes at a time.
+///
+/// # Usage
+/// ```rust
+/// # use qstr::{BStr63, IgnoreAsciiCase};
+/// use std::collections::HashSet;
+///
+/// let mut headers = HashSet::new();
+/// headers.insert(IgnoreAsciiCase(BStr63::from("Content-Type")));
+///
+/// assert!(headers.contains(&IgnoreAsciiCase(BStr63::from("content-type"))));
+/// assert_eq!(headers.iter().next().unwrap().0.as_str(), "Content-Type");
+/// ```
+#[derive(Copy, Clone, Default)]
+pub struct IgnoreAsciiCase<T>(pub T);
+
+impl<T> IgnoreAsciiCase<T> {
+  /// Returns the wrapped value
+  #[inline]
+  pub fn into_inner(self) -> T {
+    self.0
+  }
+}
+
+impl<T> From<T> for IgnoreAsciiCase<T> {
+  fn from(value: T) -> Self {
+    IgnoreAsciiCase(value)
+  }
+}
+
+impl<T: CaseInsensitive> PartialEq for IgnoreAsciiCase<T> {
+  #[inline]
+  fn eq(&self, other: &Self) -> bool {
+    self.0.case_insensitive_eq(&other.0)
+  }
+}
+
+impl<T: CaseInsensitive> Eq for IgnoreAsciiCase<T> {}
+
+impl<T: CaseInsensitive> PartialOrd for IgnoreAsciiCase<T> {
+  #[inline]
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl<T: CaseInsensitive> Ord for IgnoreAsciiCase<T> {
+  #[inline]
+  fn cmp(&self, other: &Self) -> Ordering {
+    self.0.case_insensitive_cmp(&other.0)
+  }
+}
+
+impl<T: CaseInsensitive> Hash for IgnoreAsciiCase<T> {
+  #[inline]
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.0.case_insensitive_hash(state);
+  }
+}
+
+impl<T: fmt::Display> fmt::Display for IgnoreAsciiCase<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    self.0.fmt(f)
+  }
+}
+
+impl<T: fmt::Debug> fmt::Debug for IgnoreAsciiCase<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    self.0.fmt(f)
+  }
+}
@@ -8,12 +8,45 @@
 //!
 //! ## Available types
 //! - Variable-length strings with fixed capacity ([BoundedStr])
-//! - Fixed-length strings ([FixedStr])
+//! - Fixed-length strings ([FixedStr]) with configurable padding ([Pad])
 //! - Fixed-capacity string vectors ([StrVec])
+//! - Short identifiers packed into an integer ([PackedStr])
+//! - ASCII-only strings with O(1) indexing ([AsciiBoundedStr],
+//!   [AsciiFixedStr])
+//! - Validated identifiers ([UuidStr], [UlidStr], [HexDigest])
+//! - Strings restricted by a [Validator] ([ValidatedStr])
+//! - Case-insensitive comparison and hashing ([IgnoreAsciiCase])
+//! - Small-string-optimised strings that spill over to the heap (`SpillStr`,
+//!   requires `std`)
+//! - String vectors that spill over to the heap (`SpillStrVec`, requires
+//!   `std`)
 //!
 //! ## Feature flags
 //! - `std` (default): Disable for `no_std` compatibility
 //! - `serde`: Support for serialisation/deserialisation with serde
+//! - `rkyv`: Zero-copy archiving with rkyv, including validation
+//! - `zerocopy`: Validated casting from bytes with zerocopy
+//! - `bytemuck`: Validated casting from bytes with bytemuck
+//! - `borsh`: Native borsh encoding
+//! - `bincode`: Native bincode 2 encoding
+//! - `postcard`: [MaxSize](https://docs.rs/postcard/latest/postcard/experimental/max_size/trait.MaxSize.html)
+//!   bounds for postcard
+//! - `schemars`: [JsonSchema](https://docs.rs/schemars/latest/schemars/trait.JsonSchema.html)
+//!   implementations carrying the capacity constraints
+//! - `arbitrary`, `proptest`, `quickcheck`: Generation of valid values for
+//!   fuzzing and property testing, biased towards edge cases such as full
+//!   capacity and multi-byte characters at the boundary
+//! - `defmt`, `ufmt`: Lightweight formatting for embedded targets
+//! - `heapless`, `arrayvec`: Conversions from and to their string types.
+//!   Infallible conversions check when building that capacities suffice.
+//!   Fallible `try_from_*`/`try_into_*` methods take the place of `TryFrom`.
+//! - `smol_str`, `compact_str`, `bstr`: Conversions and comparisons with their
+//!   string types
+//! - `uuid`: Conversions between [UuidStr] and `uuid::Uuid`
+//! - `derive`: Fixed-width record parsing and formatting with
+//!   `#[derive(FixedRecord)]`
+//! - `simd`: Compare, search and split strings 16 bytes at a time using SSE2
+//!   (`x86_64`) or NEON (`aarch64`) instead of 8 bytes at a time
 //!
 //! ## Minimum Supported Rust Version (MSRV)
 //! Rust v1.87+ is required due to the use of [slice::copy_from_slice].
@@ -43,15 +76,33 @@
 //! total `struct` size. The sizes were chosen with cache efficiency in mind
 //! such that most values will fit into a single cache line.
 //!
+//! # Wire format
+//! All types can be converted to and from a fixed-size byte array via
+//! `to_bytes()` and `try_from_bytes()`. The format is stable across releases
+//! and its version is given by [WIRE_FORMAT_VERSION]. The version is not
+//! embedded in the encoding, such that the size matches the in-memory size.
+//!
+//! | Type              | Size                 | Layout                                        |
+//! |-------------------|----------------------|-----------------------------------------------|
+//! | `BoundedStr<N>`   | `N + 1`              | length (`u8`), content (NUL-padded)           |
+//! | `FixedStr<N,_,P>` | `N`                  | content and padding, placed according to `P`  |
+//! | `StrVec<T, N>`    | `T::BITSIZE / 8 + N` | bitmap (little-endian `T`), data (NUL-padded) |
+//!
+//! Decoding validates all invariants, i.e. the length, UTF-8 validity, bitmap
+//! and the NUL padding of BoundedStr and StrVec.
+//!
 //! # Copy semantics
 //! Unlike `String` and `Vec<String>`, all qstr reside fully on the stack and
 //! therefore implement [Copy]. They can be passed by value or returned from
 //! functions without cloning.
 //!
 //! # Safety
-//! `unsafe` is required internally only for [str::from_utf8_unchecked] calls.
-//! The correct usage is enforced at compile time by keeping the data buffers
-//! private and marking [FixedStr::from_bytes] as `unsafe`.
+//! `unsafe` is required internally for [str::from_utf8_unchecked] calls,
+//! byte views used for word-at-a-time comparisons and, with the `simd`
+//! feature, vector intrinsics. The correct usage is enforced at compile time
+//! by keeping the data buffers private and marking [FixedStr::from_bytes] as
+//! `unsafe`. Integrations with external crates, such as `rkyv`, validate raw
+//! bytes before accepting them.
 
 #![no_std]
 #![deny(missing_docs)]
@@ -59,19 +110,64 @@
 #[cfg(feature = "std")]
 extern crate std;
 
+#[cfg(any(feature = "schemars", feature = "quickcheck"))]
+extern crate alloc;
+
+// Allows derived code to refer to `::qstr` within this crate's tests
+#[cfg(all(test, feature = "derive"))]
+extern crate self as qstr;
+
 mod alignment;
 mod alignment_resolver;
+mod ascii_bounded_str;
+mod ascii_fixed_str;
 mod bitmap;
 mod bitmap_resolver;
 mod bounded_str;
+mod codec;
 mod errors;
 mod fixed_str;
+#[cfg(any(feature = "arbitrary", feature = "proptest", feature = "quickcheck"))]
+mod generate;
+mod hex_digest;
+mod ignore_ascii_case;
+mod packed_str;
+mod padding;
+#[cfg(any(feature = "zerocopy", feature = "bytemuck"))]
+mod raw;
+#[cfg(feature = "derive")]
+mod record;
+mod search;
+#[cfg(feature = "simd")]
+mod simd;
+#[cfg(feature = "std")]
+mod spill_str;
+#[cfg(feature = "std")]
+mod spill_str_vec;
 mod str_vec;
+mod swar;
+mod transform;
+mod ulid_str;
+mod uuid_str;
+mod validated_str;
+mod validators;
 
+pub use errors::DecodeError;
 pub use errors::ExceedsCapacity;
+pub use errors::InvalidBytes;
+pub use errors::InvalidStr;
+pub use errors::ValidationError;
+
+/// Version of the wire format produced by `to_bytes()`
+///
+/// This value will be incremented whenever the encoding of any type changes.
+pub const WIRE_FORMAT_VERSION: u8 = 1;
 
 pub use bounded_str::BoundedStr;
 
+pub use ascii_bounded_str::AsciiBoundedStr;
+pub use ascii_fixed_str::AsciiFixedStr;
+
 pub use alignment::Align8;
 pub use alignment::Align16;
 pub use alignment::Align32;
@@ -104,6 +200,20 @@ pub type BStr63 = BoundedStr<63, Align64>;
 pub type BStr127 = BoundedStr<127, Align128>;
 
 pub use fixed_str::FixedStr;
+pub use padding::{Nul, Pad, Padding, RightSpace, RightZero, Space};
+
+pub use hex_digest::HexDigest;
+pub use ignore_ascii_case::{CaseInsensitive, IgnoreAsciiCase};
+pub use ulid_str::UlidStr;
+pub use uuid_str::UuidStr;
+
+pub use validated_str::{ValidatedStr, Validator};
+pub use validators::{AsciiAlphanumeric, DnsLabel, Identifier, Lowercase};
+
+#[cfg(feature = "derive")]
+pub use qstr_derive::FixedRecord;
+#[cfg(feature = "derive")]
+pub use record::FixedField;
 
 /// Fixed-length string with a capacity of 8 characters
 ///
@@ -135,8 +245,41 @@ pub type FStr64 = FixedStr<64, Align64>;
 /// Occupies 128 bytes
 pub type FStr128 = FixedStr<128, Align128>;
 
+#[cfg(feature = "std")]
+pub use spill_str::SpillStr;
+
+/// String stored inline up to 15 characters, and on the heap beyond
+#[cfg(feature = "std")]
+pub type SpillStr15 = SpillStr<15, Align16>;
+
+/// String stored inline up to 31 characters, and on the heap beyond
+#[cfg(feature = "std")]
+pub type SpillStr31 = SpillStr<31, Align32>;
+
+/// String stored inline up to 63 characters, and on the heap beyond
+#[cfg(feature = "std")]
+pub type SpillStr63 = SpillStr<63, Align64>;
+
+pub use packed_str::PackedStr;
+
+/// Identifier of up to 10 characters `[a-z0-9_.-]`, packed into a `u64`
+///
+/// Occupies 8 bytes
+pub type PackedStr10 = PackedStr<u64>;
+
+/// Identifier of up to 21 characters `[a-z0-9_.-]`, packed into a `u128`
+///
+/// Occupies 16 bytes
+pub type PackedStr21 = PackedStr<u128>;
+
 pub use str_vec::StrVec;
 
+#[cfg(feature = "rkyv")]
+pub use str_vec::ArchivedStrVec;
+
+#[cfg(any(feature = "zerocopy", feature = "bytemuck"))]
+pub use raw::{RawBoundedStr, RawFixedStr, RawStrVec};
+
 /// String vector supporting up to 28 items, with a combined capacity of 28
 /// characters
 ///
@@ -161,11 +304,87 @@ pub type StrVec56 = StrVec<u64, 56, Align64>;
 /// Fills two cache lines
 pub type StrVec112 = StrVec<u128, 112, Align128>;
 
+#[cfg(feature = "std")]
+pub use spill_str_vec::SpillStrVec;
+
+/// String vector stored inline like [StrVec28], and on the heap beyond
+#[cfg(feature = "std")]
+pub type SpillStrVec28 = SpillStrVec<u32, 28, Align32>;
+
+/// String vector stored inline like [StrVec56], and on the heap beyond
+#[cfg(feature = "std")]
+pub type SpillStrVec56 = SpillStrVec<u64, 56, Align64>;
+
+/// String vector stored inline like [StrVec112], and on the heap beyond
+#[cfg(feature = "std")]
+pub type SpillStrVec112 = SpillStrVec<u128, 112, Align128>;
+
+/// Number of bytes needed to encode `n` as a postcard varint
+#[cfg(feature = "postcard")]
+pub(crate) const fn varint_size(n: usize) -> usize {
+  let bits = usize::BITS - n.leading_zeros();
+
+  if bits == 0 {
+    1
+  } else {
+    bits.div_ceil(7) as usize
+  }
+}
+
+/// Implements [bytemuck::NoUninit] for aliases that are free of padding
+///
+/// Since padding depends on `N` and the alignment, the trait cannot be
+/// implemented generically. The size assertion guards against padding.
+#[cfg(feature = "bytemuck")]
+macro_rules! impl_padding_free {
+  ($($t:ty: $size:expr),*) => {
+    $(
+      const _: () = assert!(core::mem::size_of::<$t>() == $size);
+
+      // SAFETY: The type is `repr(C)` without padding bytes
+      unsafe impl bytemuck::NoUninit for $t {}
+    )*
+  };
+}
+
+#[cfg(feature = "bytemuck")]
+impl_padding_free!(
+  BStr7: 1 + 7,
+  BStr15: 1 + 15,
+  BStr31: 1 + 31,
+  BStr63: 1 + 63,
+  BStr127: 1 + 127,
+  FStr8: 8,
+  FStr16: 16,
+  FStr24: 24,
+  FStr32: 32,
+  FStr64: 64,
+  FStr128: 128,
+  StrVec28: 4 + 28,
+  StrVec56: 8 + 56,
+  StrVec112: 16 + 112
+);
+
 #[cfg(test)]
 mod tests {
+  mod ascii_str_tests;
   mod bounded_str_tests;
+  mod codec_tests;
+  mod compare_tests;
   mod error_tests;
   mod fixed_str_tests;
+  mod id_tests;
+  mod ignore_ascii_case_tests;
+  mod packed_str_tests;
+  #[cfg(feature = "derive")]
+  mod record_tests;
+  mod search_tests;
+  #[cfg(feature = "std")]
+  mod spill_str_tests;
+  #[cfg(feature = "std")]
+  mod spill_str_vec_tests;
   mod str_vec_tests;
+  mod transform_tests;
+  mod validated_str_tests;
   mod writer_util;
 }
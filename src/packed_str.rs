@@ -0,0 +1,300 @@
+use core::fmt;
+use core::str::FromStr;
+
+#[cfg(feature = "serde")]
+use std::string::String;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::BoundedStr;
+use crate::ExceedsCapacity;
+use crate::InvalidStr;
+use crate::ValidationError;
+use crate::bitmap::PackedBits;
+
+#[cfg(doc)]
+use crate::PackedStr10;
+#[cfg(doc)]
+use crate::PackedStr21;
+
+/// Characters indexed by their code. Code 0 marks the end of the string.
+///
+/// The characters are in ASCII order, such that comparing codes is equivalent
+/// to comparing characters.
+const CHARS: &[u8; 40] = b"\0-.0123456789_abcdefghijklmnopqrstuvwxyz";
+
+/// Number of bits per character
+const BITS_PER_CHAR: u32 = 6;
+
+/// Code of the character `c`, or 0 if `c` cannot be packed
+const fn encode_char(c: u8) -> u8 {
+  match c {
+    b'-' => 1,
+    b'.' => 2,
+    b'0'..=b'9' => c - b'0' + 3,
+    b'_' => 13,
+    b'a'..=b'z' => c - b'a' + 14,
+    _ => 0,
+  }
+}
+
+/// Short identifier of the characters `[a-z0-9_.-]`, packed into an integer
+/// with 6 bits per character
+///
+/// `T` is either `u64`, holding up to 10 characters, or `u128`, holding up to
+/// 21 characters. Equality and hashing operate on a single integer, and the
+/// numeric order matches the lexicographic order of the decoded strings.
+///
+/// # Usage
+/// ```rust
+/// # use qstr::{BStr15, PackedStr10};
+/// const BRK: PackedStr10 = PackedStr10::const_from("brk.b");
+///
+/// let s = PackedStr10::try_from("brk.a").unwrap();
+/// assert!(s < BRK);
+/// assert_eq!(s.len(), 5);
+/// assert_eq!(BStr15::from(BRK).as_str(), "brk.b");
+///
+/// assert!(PackedStr10::try_from("BRK.B").is_err());
+/// ```
+///
+/// # Aliases
+/// See [PackedStr10] and [PackedStr21].
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PackedStr<T: PackedBits>(T);
+
+macro_rules! impl_packed_str {
+  ($t:ty) => {
+    impl PackedStr<$t> {
+      /// Maximum number of characters
+      pub const CAPACITY: usize = (<$t>::BITS / BITS_PER_CHAR) as usize;
+
+      /// Create an empty PackedStr
+      #[inline]
+      pub const fn new() -> Self {
+        PackedStr(0)
+      }
+
+      /// Shift of the character at position `i`. The first character occupies
+      /// the most significant bits.
+      const fn shift(i: usize) -> u32 {
+        <$t>::BITS - BITS_PER_CHAR * (i as u32 + 1)
+      }
+
+      /// Packs `s`
+      ///
+      /// Returns `Err` if `s` exceeds the capacity or contains characters
+      /// other than `[a-z0-9_.-]`.
+      pub const fn try_from(s: &str) -> Result<Self, ValidationError<InvalidStr>> {
+        let bytes = s.as_bytes();
+
+        if bytes.len() > Self::CAPACITY {
+          return Err(ValidationError::ExceedsCapacity(ExceedsCapacity {
+            length: bytes.len(),
+            capacity: Self::CAPACITY,
+          }));
+        }
+
+        let mut bits: $t = 0;
+        let mut i = 0;
+
+        while i < bytes.len() {
+          let code = encode_char(bytes[i]);
+
+          if code == 0 {
+            return Err(ValidationError::Invalid(InvalidStr { offset: i }));
+          }
+
+          bits |= (code as $t) << Self::shift(i);
+          i += 1;
+        }
+
+        Ok(PackedStr(bits))
+      }
+
+      /// Packs `s` in a const context
+      ///
+      /// # Panics
+      /// This will panic if `s` exceeds the capacity or contains characters
+      /// other than `[a-z0-9_.-]`.
+      pub const fn const_from(s: &str) -> Self {
+        match Self::try_from(s) {
+          Ok(s) => s,
+          Err(ValidationError::ExceedsCapacity(_)) => panic!("String length exceeds capacity"),
+          Err(ValidationError::Invalid(_)) => panic!("String contains unpackable characters"),
+        }
+      }
+
+      /// Restores a PackedStr from its integer representation
+      ///
+      /// Returns `None` if `bits` contains invalid codes, non-zero unused bits
+      /// or characters after the end of the string.
+      pub const fn try_from_bits(bits: $t) -> Option<Self> {
+        let unused = <$t>::BITS - BITS_PER_CHAR * Self::CAPACITY as u32;
+
+        if bits & ((1 << unused) - 1) != 0 {
+          return None;
+        }
+
+        let mut ended = false;
+        let mut i = 0;
+
+        while i < Self::CAPACITY {
+          let code = ((bits >> Self::shift(i)) & 0x3f) as usize;
+
+          if code >= CHARS.len() || (ended && code != 0) {
+            return None;
+          }
+
+          ended = code == 0;
+          i += 1;
+        }
+
+        Some(PackedStr(bits))
+      }
+
+      /// Returns the integer representation
+      #[inline]
+      pub const fn to_bits(self) -> $t {
+        self.0
+      }
+
+      /// Returns the number of characters
+      #[inline]
+      pub const fn len(&self) -> usize {
+        // All codes before the end are non-zero
+        (<$t>::BITS - self.0.trailing_zeros()).div_ceil(BITS_PER_CHAR) as usize
+      }
+
+      /// Checks if the PackedStr is empty
+      #[inline]
+      pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+      }
+
+      /// Returns the character at position `i`, or `None` if out of bounds
+      #[inline]
+      pub const fn char_at(&self, i: usize) -> Option<char> {
+        if i >= self.len() {
+          return None;
+        }
+
+        let code = (self.0 >> Self::shift(i)) & 0x3f;
+        Some(CHARS[code as usize] as char)
+      }
+
+      /// Unpacks the string into a BoundedStr of any capacity
+      ///
+      /// Returns `Err` if the string exceeds `N`. This takes the place of
+      /// [TryFrom], which would conflict with the [From] impl for capacities
+      /// that hold [Self::CAPACITY] characters.
+      pub fn try_into_bounded<const N: usize, Alignment>(
+        &self,
+      ) -> Result<BoundedStr<N, Alignment>, ExceedsCapacity> {
+        self.with_str(BoundedStr::try_from)
+      }
+
+      /// Unpacks the characters into a buffer, returning it with the length
+      const fn decode(&self) -> ([u8; Self::CAPACITY], usize) {
+        let mut buf = [0u8; Self::CAPACITY];
+        let len = self.len();
+        let mut i = 0;
+
+        while i < len {
+          buf[i] = CHARS[((self.0 >> Self::shift(i)) & 0x3f) as usize];
+          i += 1;
+        }
+
+        (buf, len)
+      }
+
+      /// Calls `f` with the unpacked string
+      fn with_str<R>(&self, f: impl FnOnce(&str) -> R) -> R {
+        let (buf, len) = self.decode();
+
+        // SAFETY: All packable characters are ASCII
+        f(unsafe { core::str::from_utf8_unchecked(&buf[..len]) })
+      }
+    }
+
+    impl fmt::Display for PackedStr<$t> {
+      fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.with_str(|s| f.write_str(s))
+      }
+    }
+
+    impl fmt::Debug for PackedStr<$t> {
+      fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+      }
+    }
+
+    impl FromStr for PackedStr<$t> {
+      type Err = ValidationError<InvalidStr>;
+
+      fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+      }
+    }
+
+    impl TryFrom<&str> for PackedStr<$t> {
+      type Error = ValidationError<InvalidStr>;
+
+      fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::try_from(s)
+      }
+    }
+
+    impl<const N: usize, Alignment> TryFrom<BoundedStr<N, Alignment>> for PackedStr<$t> {
+      type Error = ValidationError<InvalidStr>;
+
+      fn try_from(s: BoundedStr<N, Alignment>) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+      }
+    }
+
+    /// Requires that the BoundedStr can hold [PackedStr::CAPACITY] characters
+    ///
+    /// The impl exists for all capacities. Others are only rejected once the
+    /// conversion is built, e.g. by `cargo build` but not `cargo check`. Use
+    /// [PackedStr::try_into_bounded] for them instead.
+    impl<const N: usize, Alignment> From<PackedStr<$t>> for BoundedStr<N, Alignment> {
+      fn from(s: PackedStr<$t>) -> Self {
+        const {
+          assert!(
+            N >= PackedStr::<$t>::CAPACITY,
+            "BoundedStr capacity is too small"
+          )
+        };
+
+        // Cannot fail due to the assertion
+        s.with_str(|s| BoundedStr::try_from(s).unwrap())
+      }
+    }
+
+    #[cfg(feature = "serde")]
+    impl Serialize for PackedStr<$t> {
+      fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+      where
+        S: serde::Serializer,
+      {
+        self.with_str(|s| s.serialize(serializer))
+      }
+    }
+
+    #[cfg(feature = "serde")]
+    impl<'de> Deserialize<'de> for PackedStr<$t> {
+      fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+      where
+        D: serde::Deserializer<'de>,
+      {
+        let s = String::deserialize(deserializer)?;
+        PackedStr::<$t>::try_from(s.as_str()).map_err(serde::de::Error::custom)
+      }
+    }
+  };
+}
+
+impl_packed_str!(u64);
+impl_packed_str!(u128);
@@ -0,0 +1,64 @@
+/// Padding policy of a [FixedStr](crate::FixedStr)
+///
+/// Determines the byte that fills unused space and on which side it is
+/// placed. The trait is sealed, since FixedStr relies on the padding byte
+/// being ASCII. Custom policies are expressed through [Pad] instead:
+/// ```compile_fail
+/// # use qstr::Padding;
+/// struct Custom;
+///
+/// impl Padding for Custom {
+///   const BYTE: u8 = 0xff;
+///   const RIGHT_ALIGNED: bool = false;
+/// }
+/// ```
+pub trait Padding: sealed::Sealed {
+  /// Byte that fills unused space. Must be ASCII.
+  const BYTE: u8;
+
+  /// If set, the content is right-aligned and the padding precedes it
+  const RIGHT_ALIGNED: bool;
+}
+
+/// Zero-sized padding policy using `BYTE`, which must be ASCII
+///
+/// By default, the content is left-aligned and followed by the padding. If
+/// `RIGHT_ALIGNED` is set, the padding precedes the content instead, as is
+/// common for numeric fields.
+///
+/// Non-ASCII padding bytes are rejected at compile time:
+/// ```compile_fail
+/// # use qstr::{Align8, FixedStr, Pad};
+/// let s = FixedStr::<8, Align8, Pad<0xff>>::new();
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Debug, Default, Hash)]
+pub struct Pad<const BYTE: u8, const RIGHT_ALIGNED: bool = false>;
+
+mod sealed {
+  /// Restricts [Padding](super::Padding) to [Pad](super::Pad)
+  pub trait Sealed {}
+
+  impl<const BYTE: u8, const RIGHT_ALIGNED: bool> Sealed for super::Pad<BYTE, RIGHT_ALIGNED> {}
+}
+
+impl<const BYTE: u8, const RIGHT_ALIGNED: bool> Padding for Pad<BYTE, RIGHT_ALIGNED> {
+  const BYTE: u8 = {
+    assert!(BYTE.is_ascii(), "Padding byte must be ASCII");
+    BYTE
+  };
+
+  const RIGHT_ALIGNED: bool = RIGHT_ALIGNED;
+}
+
+/// Left-aligned content followed by NUL bytes (default)
+pub type Nul = Pad<0>;
+
+/// Left-aligned content followed by spaces, e.g. FIX or COBOL `PIC X` fields
+pub type Space = Pad<b' '>;
+
+/// Right-aligned content preceded by spaces
+pub type RightSpace = Pad<b' ', true>;
+
+/// Right-aligned content preceded by zeros, e.g. ISO 8583 or COBOL `PIC 9`
+/// fields
+pub type RightZero = Pad<b'0', true>;
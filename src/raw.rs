@@ -0,0 +1,76 @@
+//! Unvalidated counterparts of the qstr types
+//!
+//! The raw types share the exact memory layout of [BoundedStr], [FixedStr] and
+//! [StrVec], but accept any bit pattern. They serve as an intermediate step
+//! when casting bytes, after which the invariants are checked before handing
+//! out a reference to the validated type.
+
+#[cfg(doc)]
+use crate::{BoundedStr, FixedStr, StrVec};
+
+/// Unvalidated bit pattern of a [BoundedStr]
+#[derive(Copy, Clone)]
+#[cfg_attr(
+  feature = "zerocopy",
+  derive(zerocopy::FromBytes, zerocopy::KnownLayout, zerocopy::Immutable)
+)]
+#[repr(C)]
+pub struct RawBoundedStr<const N: usize, Alignment> {
+  pub(crate) length: u8,
+  pub(crate) data: [u8; N],
+  align: [Alignment; 0],
+}
+
+/// Unvalidated bit pattern of a [FixedStr]
+#[derive(Copy, Clone)]
+#[cfg_attr(
+  feature = "zerocopy",
+  derive(zerocopy::FromBytes, zerocopy::KnownLayout, zerocopy::Immutable)
+)]
+#[repr(C)]
+pub struct RawFixedStr<const N: usize, Alignment> {
+  pub(crate) data: [u8; N],
+  align: [Alignment; 0],
+}
+
+/// Unvalidated bit pattern of a [StrVec]
+#[derive(Copy, Clone)]
+#[cfg_attr(
+  feature = "zerocopy",
+  derive(zerocopy::FromBytes, zerocopy::KnownLayout, zerocopy::Immutable)
+)]
+#[repr(C)]
+pub struct RawStrVec<T, const N: usize, Alignment> {
+  pub(crate) bitmap: T,
+  pub(crate) data: [u8; N],
+  align: [Alignment; 0],
+}
+
+// SAFETY: All fields are integers or byte arrays, for which every bit pattern
+//         (including all zeros) is valid.
+#[cfg(feature = "bytemuck")]
+unsafe impl<const N: usize, Alignment> bytemuck::Zeroable for RawBoundedStr<N, Alignment> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<const N: usize, Alignment: Copy + 'static> bytemuck::AnyBitPattern
+  for RawBoundedStr<N, Alignment>
+{
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<const N: usize, Alignment> bytemuck::Zeroable for RawFixedStr<N, Alignment> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<const N: usize, Alignment: Copy + 'static> bytemuck::AnyBitPattern
+  for RawFixedStr<N, Alignment>
+{
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Zeroable, const N: usize, Alignment> bytemuck::Zeroable
+  for RawStrVec<T, N, Alignment>
+{
+}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::AnyBitPattern, const N: usize, Alignment: Copy + 'static>
+  bytemuck::AnyBitPattern for RawStrVec<T, N, Alignment>
+{
+}
@@ -0,0 +1,123 @@
+use crate::BoundedStr;
+use crate::FixedStr;
+use crate::InvalidBytes;
+use crate::padding::Padding;
+
+#[cfg(doc)]
+use crate::FixedRecord;
+
+/// Field of a fixed-width record
+///
+/// A field occupies exactly [Self::SIZE] bytes. Shorter content is padded with
+/// [Self::PAD], either after the content or, if [Self::RIGHT_ALIGNED] is set,
+/// before it. Both can be overridden per field with the `#[record(...)]`
+/// attribute of [FixedRecord].
+///
+/// # Usage
+/// ```rust
+/// use qstr::{Align8, BStr7, FStr8, FixedRecord, FixedStr, RightZero};
+///
+/// #[derive(FixedRecord)]
+/// struct Trade {
+///   #[record(pad = ' ')]
+///   symbol: FStr8,
+///   amount: FixedStr<6, Align8, RightZero>,
+///   #[record(pad = '*', align = "right")]
+///   desk: BStr7,
+/// }
+///
+/// let trade = Trade::parse(b"ACME    001250**LDN01").unwrap();
+/// assert_eq!(trade.symbol.trimmed(), "ACME");
+/// assert_eq!(trade.amount.trimmed(), "1250");
+/// assert_eq!(trade.desk.as_str(), "LDN01");
+///
+/// assert_eq!(Trade::SIZE, 21);
+/// assert_eq!(&trade.write(), b"ACME    001250**LDN01");
+/// ```
+///
+/// Content that starts or ends with the padding byte on the padded side cannot
+/// be told apart from padding and is trimmed when parsing.
+pub trait FixedField: Sized {
+  /// Width of the field in bytes
+  const SIZE: usize;
+
+  /// Default padding byte
+  const PAD: u8;
+
+  /// Whether the content is right-aligned by default
+  const RIGHT_ALIGNED: bool;
+
+  /// Parses the field from `bytes`, removing the padding
+  ///
+  /// `bytes` must be [Self::SIZE] bytes long. Offsets in errors are relative
+  /// to `bytes`.
+  fn parse_field(bytes: &[u8], pad: u8, right_aligned: bool) -> Result<Self, InvalidBytes>;
+
+  /// Writes the field to `out`, filling unused space with `pad`
+  ///
+  /// `out` must be [Self::SIZE] bytes long.
+  fn write_field(&self, out: &mut [u8], pad: u8, right_aligned: bool);
+}
+
+/// Validates `bytes` and removes the padding
+///
+/// A right-aligned field consisting of zeros only holds the number zero, so
+/// its last digit is kept.
+fn unpad(bytes: &[u8], pad: u8, right_aligned: bool) -> Result<&str, InvalidBytes> {
+  let s = core::str::from_utf8(bytes).map_err(|e| InvalidBytes::InvalidUtf8 {
+    offset: e.valid_up_to(),
+  })?;
+
+  if !right_aligned {
+    return Ok(s.trim_end_matches(pad as char));
+  }
+
+  match s.trim_start_matches(pad as char) {
+    "" if pad == b'0' => Ok(&s[s.len().saturating_sub(1)..]),
+    content => Ok(content),
+  }
+}
+
+/// Writes `s` to `out` and fills the remaining bytes with `pad`
+fn write_padded(out: &mut [u8], s: &str, pad: u8, right_aligned: bool) {
+  let (content, padding) = if right_aligned {
+    let (padding, content) = out.split_at_mut(out.len() - s.len());
+    (content, padding)
+  } else {
+    out.split_at_mut(s.len())
+  };
+
+  content.copy_from_slice(s.as_bytes());
+  padding.fill(pad);
+}
+
+impl<const N: usize, Alignment, P: Padding> FixedField for FixedStr<N, Alignment, P> {
+  const SIZE: usize = N;
+  const PAD: u8 = P::BYTE;
+  const RIGHT_ALIGNED: bool = P::RIGHT_ALIGNED;
+
+  fn parse_field(bytes: &[u8], pad: u8, right_aligned: bool) -> Result<Self, InvalidBytes> {
+    let s = unpad(bytes, pad, right_aligned)?;
+    Self::try_from(s).map_err(InvalidBytes::ExceedsCapacity)
+  }
+
+  fn write_field(&self, out: &mut [u8], pad: u8, right_aligned: bool) {
+    write_padded(out, self.trimmed(), pad, right_aligned);
+  }
+}
+
+/// Fields are NUL-padded by default
+impl<const N: usize, Alignment> FixedField for BoundedStr<N, Alignment> {
+  const SIZE: usize = N;
+  const PAD: u8 = 0;
+  const RIGHT_ALIGNED: bool = false;
+
+  fn parse_field(bytes: &[u8], pad: u8, right_aligned: bool) -> Result<Self, InvalidBytes> {
+    let s = unpad(bytes, pad, right_aligned)?;
+    Self::try_from(s).map_err(InvalidBytes::ExceedsCapacity)
+  }
+
+  fn write_field(&self, out: &mut [u8], pad: u8, right_aligned: bool) {
+    write_padded(out, self.as_str(), pad, right_aligned);
+  }
+}
@@ -0,0 +1,267 @@
+//! Substring search processing a block of bytes per step
+//!
+//! Candidates are located by comparing a whole block against the needle's
+//! first byte, which yields a mask with [Matcher::MASK_BITS] bits per matching
+//! byte. Each candidate is then verified against the full needle.
+
+use core::marker::PhantomData;
+
+use crate::swar;
+
+/// Strategy for locating a byte within a block
+pub(crate) trait Matcher {
+  /// Number of bytes per block
+  const BLOCK: usize;
+
+  /// Number of mask bits per byte
+  const MASK_BITS: u32;
+
+  /// Sets the mask bits of each byte in `block` that equals `b`, starting with
+  /// the least significant bits for the first byte
+  ///
+  /// `block` holds at most [Matcher::BLOCK] bytes.
+  fn eq_mask(block: &[u8], b: u8) -> u64;
+}
+
+/// Compares one byte at a time, serving as the reference in tests
+#[cfg(test)]
+pub(crate) struct Scalar;
+
+#[cfg(test)]
+impl Matcher for Scalar {
+  const BLOCK: usize = 8;
+  const MASK_BITS: u32 = 1;
+
+  #[inline]
+  fn eq_mask(block: &[u8], b: u8) -> u64 {
+    block
+      .iter()
+      .enumerate()
+      .fold(0, |mask, (i, &c)| mask | ((c == b) as u64) << i)
+  }
+}
+
+/// Compares eight bytes at a time within a `u64`
+#[cfg_attr(feature = "simd", allow(dead_code))]
+pub(crate) struct Swar;
+
+impl Matcher for Swar {
+  const BLOCK: usize = 8;
+  const MASK_BITS: u32 = 8;
+
+  #[inline]
+  fn eq_mask(block: &[u8], b: u8) -> u64 {
+    let word = match block.try_into() {
+      Ok(word) => u64::from_le_bytes(word),
+      Err(_) => {
+        let mut word = [0; 8];
+        word[..block.len()].copy_from_slice(block);
+        u64::from_le_bytes(word)
+      }
+    };
+
+    swar::eq_mask(word, b)
+  }
+}
+
+/// Compares 16 bytes at a time using SSE2 or NEON
+#[cfg(feature = "simd")]
+pub(crate) struct Simd;
+
+#[cfg(feature = "simd")]
+impl Matcher for Simd {
+  const BLOCK: usize = crate::simd::LANES;
+  const MASK_BITS: u32 = crate::simd::MASK_BITS;
+
+  #[inline]
+  fn eq_mask(block: &[u8], b: u8) -> u64 {
+    match block.try_into() {
+      Ok(block) => crate::simd::eq_mask(block, b),
+      Err(_) => {
+        let mut padded = [0; crate::simd::LANES];
+        padded[..block.len()].copy_from_slice(block);
+        crate::simd::eq_mask(&padded, b)
+      }
+    }
+  }
+}
+
+/// Fastest strategy for the enabled features
+#[cfg(feature = "simd")]
+pub(crate) type Native = Simd;
+
+/// Fastest strategy for the enabled features
+#[cfg(not(feature = "simd"))]
+pub(crate) type Native = Swar;
+
+/// Mask of the bytes equal to `b` in the block starting at `offset`
+#[inline]
+fn block_mask<M: Matcher>(haystack: &[u8], offset: usize, b: u8) -> u64 {
+  let block = &haystack[offset..haystack.len().min(offset + M::BLOCK)];
+  let mask = M::eq_mask(block, b);
+  let bits = block.len() as u32 * M::MASK_BITS;
+
+  // Ignore the padding of a partial block
+  if bits >= u64::BITS {
+    mask
+  } else {
+    mask & ((1 << bits) - 1)
+  }
+}
+
+/// Mask bits of the byte at position `i` within a block
+#[inline]
+fn byte_bits<M: Matcher>(i: usize) -> u64 {
+  (u64::MAX >> (u64::BITS - M::MASK_BITS)) << (i as u32 * M::MASK_BITS)
+}
+
+/// Returns the offset of the first occurrence of `needle` in `haystack`
+pub(crate) fn find<M: Matcher>(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+  let Some(&first) = needle.first() else {
+    return Some(0);
+  };
+
+  // Only these positions leave enough room for the needle
+  let starts = &haystack[..=haystack.len().checked_sub(needle.len())?];
+
+  for offset in (0..starts.len()).step_by(M::BLOCK) {
+    let mut mask = block_mask::<M>(starts, offset, first);
+
+    while mask != 0 {
+      let i = (mask.trailing_zeros() / M::MASK_BITS) as usize;
+
+      if haystack[offset + i..].starts_with(needle) {
+        return Some(offset + i);
+      }
+
+      mask &= !byte_bits::<M>(i);
+    }
+  }
+
+  None
+}
+
+/// Returns the offset of the last occurrence of `needle` in `haystack`
+pub(crate) fn rfind<M: Matcher>(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+  let Some(&first) = needle.first() else {
+    return Some(haystack.len());
+  };
+
+  let starts = &haystack[..=haystack.len().checked_sub(needle.len())?];
+
+  for offset in (0..starts.len()).step_by(M::BLOCK).rev() {
+    let mut mask = block_mask::<M>(starts, offset, first);
+
+    while mask != 0 {
+      let i = ((u64::BITS - 1 - mask.leading_zeros()) / M::MASK_BITS) as usize;
+
+      if haystack[offset + i..].starts_with(needle) {
+        return Some(offset + i);
+      }
+
+      mask &= !byte_bits::<M>(i);
+    }
+  }
+
+  None
+}
+
+/// Iterator over the offsets of a byte, consuming one match mask per block
+pub(crate) struct ByteIndices<'a, M> {
+  haystack: &'a [u8],
+  byte: u8,
+
+  /// Offset of the block that `mask` belongs to
+  offset: usize,
+
+  /// Remaining matches within the current block
+  mask: u64,
+
+  matcher: PhantomData<M>,
+}
+
+impl<'a, M: Matcher> ByteIndices<'a, M> {
+  pub(crate) fn new(haystack: &'a [u8], byte: u8) -> Self {
+    ByteIndices {
+      haystack,
+      byte,
+      offset: 0,
+      mask: if haystack.is_empty() {
+        0
+      } else {
+        block_mask::<M>(haystack, 0, byte)
+      },
+      matcher: PhantomData,
+    }
+  }
+}
+
+impl<M: Matcher> Iterator for ByteIndices<'_, M> {
+  type Item = usize;
+
+  #[inline]
+  fn next(&mut self) -> Option<usize> {
+    while self.mask == 0 {
+      if self.offset + M::BLOCK >= self.haystack.len() {
+        return None;
+      }
+
+      self.offset += M::BLOCK;
+      self.mask = block_mask::<M>(self.haystack, self.offset, self.byte);
+    }
+
+    let i = (self.mask.trailing_zeros() / M::MASK_BITS) as usize;
+    self.mask &= !byte_bits::<M>(i);
+    Some(self.offset + i)
+  }
+}
+
+/// Iterator over the offsets of non-overlapping occurrences of a needle,
+/// equivalent to [str::match_indices]
+pub(crate) struct MatchIndices<'a, M> {
+  haystack: &'a str,
+  needle: &'a str,
+
+  /// Offset at which the search continues, beyond the haystack once exhausted
+  position: usize,
+
+  matcher: PhantomData<M>,
+}
+
+impl<'a, M: Matcher> MatchIndices<'a, M> {
+  pub(crate) fn new(haystack: &'a str, needle: &'a str) -> Self {
+    MatchIndices {
+      haystack,
+      needle,
+      position: 0,
+      matcher: PhantomData,
+    }
+  }
+}
+
+impl<M: Matcher> Iterator for MatchIndices<'_, M> {
+  type Item = usize;
+
+  fn next(&mut self) -> Option<usize> {
+    let rest = self.haystack.get(self.position..)?;
+
+    if self.needle.is_empty() {
+      // An empty needle matches at every character boundary
+      let offset = self.position;
+      self.position += rest.chars().next().map_or(1, char::len_utf8);
+      return Some(offset);
+    }
+
+    match find::<M>(rest.as_bytes(), self.needle.as_bytes()) {
+      Some(i) => {
+        let offset = self.position + i;
+        self.position = offset + self.needle.len();
+        Some(offset)
+      }
+      None => {
+        self.position = self.haystack.len() + 1;
+        None
+      }
+    }
+  }
+}
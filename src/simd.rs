@@ -0,0 +1,148 @@
+//! Vectorised byte comparisons and searches using `core::arch`, processing
+//! 16 bytes per step
+//!
+//! SSE2 and NEON are part of the baseline of `x86_64` and `aarch64`
+//! respectively. Other targets fall back to a scalar implementation.
+
+/// Number of bytes per vector
+pub(crate) const LANES: usize = 16;
+
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+mod arch {
+  use core::arch::x86_64::{
+    __m128i, _mm_cmpeq_epi8, _mm_loadu_si128, _mm_movemask_epi8, _mm_set1_epi8,
+  };
+
+  /// Number of mask bits per byte
+  pub(super) const MASK_BITS: u32 = 1;
+
+  /// Sets the mask bits of each byte that differs between `a` and `b`
+  #[inline]
+  pub(super) fn mismatch_mask(a: &[u8; super::LANES], b: &[u8; super::LANES]) -> u64 {
+    // SAFETY: SSE2 is available, and the loads permit unaligned addresses
+    let equal = unsafe {
+      let a = _mm_loadu_si128(a.as_ptr().cast::<__m128i>());
+      let b = _mm_loadu_si128(b.as_ptr().cast::<__m128i>());
+      _mm_movemask_epi8(_mm_cmpeq_epi8(a, b)) as u32
+    };
+
+    (!equal & 0xffff) as u64
+  }
+
+  /// Sets the mask bits of each byte in `block` that equals `b`
+  #[inline]
+  pub(super) fn eq_mask(block: &[u8; super::LANES], b: u8) -> u64 {
+    // SAFETY: SSE2 is available, and the load permits unaligned addresses
+    let equal = unsafe {
+      let block = _mm_loadu_si128(block.as_ptr().cast::<__m128i>());
+      _mm_movemask_epi8(_mm_cmpeq_epi8(block, _mm_set1_epi8(b as i8))) as u32
+    };
+
+    equal as u64
+  }
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+mod arch {
+  use core::arch::aarch64::{
+    uint8x16_t, vceqq_u8, vdupq_n_u8, vget_lane_u64, vld1q_u8, vreinterpret_u64_u8,
+    vreinterpretq_u16_u8, vshrn_n_u16,
+  };
+
+  /// Number of mask bits per byte
+  pub(super) const MASK_BITS: u32 = 4;
+
+  /// Narrows a comparison result to four bits per byte
+  #[inline]
+  fn to_mask(equal: uint8x16_t) -> u64 {
+    // SAFETY: NEON is available
+    unsafe {
+      let narrowed = vshrn_n_u16::<4>(vreinterpretq_u16_u8(equal));
+      vget_lane_u64::<0>(vreinterpret_u64_u8(narrowed))
+    }
+  }
+
+  /// Sets the mask bits of each byte that differs between `a` and `b`
+  #[inline]
+  pub(super) fn mismatch_mask(a: &[u8; super::LANES], b: &[u8; super::LANES]) -> u64 {
+    // SAFETY: NEON is available, and the loads permit unaligned addresses
+    let equal = unsafe { vceqq_u8(vld1q_u8(a.as_ptr()), vld1q_u8(b.as_ptr())) };
+    !to_mask(equal)
+  }
+
+  /// Sets the mask bits of each byte in `block` that equals `b`
+  #[inline]
+  pub(super) fn eq_mask(block: &[u8; super::LANES], b: u8) -> u64 {
+    // SAFETY: NEON is available, and the load permits unaligned addresses
+    let equal = unsafe { vceqq_u8(vld1q_u8(block.as_ptr()), vdupq_n_u8(b)) };
+    to_mask(equal)
+  }
+}
+
+#[cfg(not(any(
+  all(target_arch = "x86_64", target_feature = "sse2"),
+  all(target_arch = "aarch64", target_feature = "neon")
+)))]
+mod arch {
+  /// Number of mask bits per byte
+  pub(super) const MASK_BITS: u32 = 1;
+
+  /// Sets the mask bits of each byte that differs between `a` and `b`
+  #[inline]
+  pub(super) fn mismatch_mask(a: &[u8; super::LANES], b: &[u8; super::LANES]) -> u64 {
+    a.iter()
+      .zip(b)
+      .enumerate()
+      .fold(0, |mask, (i, (a, b))| mask | ((a != b) as u64) << i)
+  }
+
+  /// Sets the mask bits of each byte in `block` that equals `b`
+  #[inline]
+  pub(super) fn eq_mask(block: &[u8; super::LANES], b: u8) -> u64 {
+    block
+      .iter()
+      .enumerate()
+      .fold(0, |mask, (i, &c)| mask | ((c == b) as u64) << i)
+  }
+}
+
+/// Number of mask bits per byte
+pub(crate) const MASK_BITS: u32 = arch::MASK_BITS;
+
+/// Sets the [MASK_BITS] mask bits of each byte in `block` that equals `b`,
+/// starting with the least significant bits for the first byte
+#[inline]
+pub(crate) fn eq_mask(block: &[u8; LANES], b: u8) -> u64 {
+  arch::eq_mask(block, b)
+}
+
+/// Returns the offset of the first byte that differs between `a` and `b`,
+/// which must have the same length
+#[inline]
+pub(crate) fn first_mismatch(a: &[u8], b: &[u8]) -> Option<usize> {
+  debug_assert_eq!(a.len(), b.len());
+
+  let a_blocks = a.chunks_exact(LANES);
+  let b_blocks = b.chunks_exact(LANES);
+  let a_rest = a_blocks.remainder();
+  let b_rest = b_blocks.remainder();
+  let rest_offset = a.len() - a_rest.len();
+
+  for (i, (a, b)) in a_blocks.zip(b_blocks).enumerate() {
+    // chunks_exact() only yields slices of LANES bytes
+    let mask = arch::mismatch_mask(
+      a.try_into().unwrap_or(&[0; LANES]),
+      b.try_into().unwrap_or(&[0; LANES]),
+    );
+
+    if mask != 0 {
+      return Some(i * LANES + (mask.trailing_zeros() / arch::MASK_BITS) as usize);
+    }
+  }
+
+  a_rest
+    .iter()
+    .zip(b_rest)
+    .position(|(a, b)| a != b)
+    .map(|i| rest_offset + i)
+}
@@ -0,0 +1,239 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+use std::string::String;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::BoundedStr;
+use crate::ExceedsCapacity;
+
+#[cfg(doc)]
+use crate::SpillStr15;
+#[cfg(doc)]
+use crate::SpillStr31;
+#[cfg(doc)]
+use crate::SpillStr63;
+
+/// Small-string-optimised string that spills over to the heap
+///
+/// Strings of up to `N` bytes are stored inline in a [BoundedStr]. Longer
+/// strings are moved to a heap-allocated [String]. Unlike [BoundedStr], all
+/// operations succeed regardless of the length.
+///
+/// # Usage
+/// ```rust
+/// # use qstr::SpillStr15;
+/// let mut s = SpillStr15::from("hello");
+/// assert!(s.is_inline());
+///
+/// s.push_str(", wonderful world!");
+/// assert!(!s.is_inline());
+/// assert_eq!(s.as_str(), "hello, wonderful world!");
+/// ```
+///
+/// # Aliases
+/// The aliases [SpillStr15], [SpillStr31] and [SpillStr63] use the inline
+/// capacities of the corresponding BoundedStr aliases.
+#[derive(Clone)]
+pub struct SpillStr<const N: usize, Alignment> {
+  /// Only holds a heap string if it exceeds `N` bytes
+  repr: Repr<N, Alignment>,
+}
+
+#[derive(Clone)]
+enum Repr<const N: usize, Alignment> {
+  Inline(BoundedStr<N, Alignment>),
+  Heap(String),
+}
+
+impl<const N: usize, Alignment> SpillStr<N, Alignment> {
+  /// Create an empty SpillStr
+  #[inline]
+  pub const fn new() -> Self {
+    Self {
+      repr: Repr::Inline(BoundedStr::new()),
+    }
+  }
+
+  /// Checks whether the string is stored inline rather than on the heap
+  #[inline]
+  pub fn is_inline(&self) -> bool {
+    matches!(self.repr, Repr::Inline(_))
+  }
+
+  /// Convert SpillStr to `&str`
+  #[inline]
+  pub fn as_str(&self) -> &str {
+    match &self.repr {
+      Repr::Inline(s) => s.as_str(),
+      Repr::Heap(s) => s.as_str(),
+    }
+  }
+
+  /// Returns the length in bytes
+  #[inline]
+  pub fn len(&self) -> usize {
+    self.as_str().len()
+  }
+
+  /// Checks if the string is empty
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// Appends a string slice, moving the string to the heap if it no longer
+  /// fits inline
+  pub fn push_str(&mut self, s: &str) {
+    match &mut self.repr {
+      Repr::Inline(inline) => {
+        if inline.push_str(s).is_err() {
+          let mut heap = String::with_capacity(inline.len() + s.len());
+          heap.push_str(inline.as_str());
+          heap.push_str(s);
+
+          self.repr = Repr::Heap(heap);
+        }
+      }
+      Repr::Heap(heap) => heap.push_str(s),
+    }
+  }
+
+  /// Appends a single character, moving the string to the heap if it no
+  /// longer fits inline
+  pub fn push(&mut self, c: char) {
+    let mut buf = [0u8; 4];
+    self.push_str(c.encode_utf8(&mut buf));
+  }
+
+  /// Removes the content. The string is stored inline again afterwards.
+  pub fn clear(&mut self) {
+    *self = Self::new();
+  }
+}
+
+impl<const N: usize, Alignment> Default for SpillStr<N, Alignment> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<const N: usize, Alignment> PartialEq for SpillStr<N, Alignment> {
+  fn eq(&self, other: &Self) -> bool {
+    self.as_str() == other.as_str()
+  }
+}
+
+impl<const N: usize, Alignment> Eq for SpillStr<N, Alignment> {}
+
+/// Like [BoundedStr], values are ordered by their length first
+impl<const N: usize, Alignment> Ord for SpillStr<N, Alignment> {
+  fn cmp(&self, other: &Self) -> Ordering {
+    (self.len(), self.as_str()).cmp(&(other.len(), other.as_str()))
+  }
+}
+
+impl<const N: usize, Alignment> PartialOrd for SpillStr<N, Alignment> {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl<const N: usize, Alignment> Hash for SpillStr<N, Alignment> {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.as_str().hash(state);
+  }
+}
+
+impl<const N: usize, Alignment> fmt::Display for SpillStr<N, Alignment> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+impl<const N: usize, Alignment> fmt::Debug for SpillStr<N, Alignment> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+impl<const N: usize, Alignment> From<&str> for SpillStr<N, Alignment> {
+  fn from(s: &str) -> Self {
+    let mut result = Self::new();
+    result.push_str(s);
+    result
+  }
+}
+
+impl<const N: usize, Alignment> From<&String> for SpillStr<N, Alignment> {
+  fn from(s: &String) -> Self {
+    Self::from(s.as_str())
+  }
+}
+
+/// Reuses the allocation if the string does not fit inline
+impl<const N: usize, Alignment> From<String> for SpillStr<N, Alignment> {
+  fn from(s: String) -> Self {
+    let repr = match BoundedStr::try_from(s.as_str()) {
+      Ok(inline) => Repr::Inline(inline),
+      Err(_) => Repr::Heap(s),
+    };
+
+    Self { repr }
+  }
+}
+
+impl<const N: usize, Alignment> From<BoundedStr<N, Alignment>> for SpillStr<N, Alignment> {
+  fn from(s: BoundedStr<N, Alignment>) -> Self {
+    Self {
+      repr: Repr::Inline(s),
+    }
+  }
+}
+
+/// Succeeds if the string is stored inline
+impl<const N: usize, Alignment> TryFrom<SpillStr<N, Alignment>> for BoundedStr<N, Alignment> {
+  type Error = ExceedsCapacity;
+
+  fn try_from(s: SpillStr<N, Alignment>) -> Result<Self, Self::Error> {
+    match s.repr {
+      Repr::Inline(inline) => Ok(inline),
+      Repr::Heap(heap) => Err(ExceedsCapacity {
+        length: heap.len(),
+        capacity: N,
+      }),
+    }
+  }
+}
+
+impl<const N: usize, Alignment> From<SpillStr<N, Alignment>> for String {
+  fn from(s: SpillStr<N, Alignment>) -> Self {
+    match s.repr {
+      Repr::Inline(inline) => String::from(inline.as_str()),
+      Repr::Heap(heap) => heap,
+    }
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<const N: usize, Alignment> Serialize for SpillStr<N, Alignment> {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    self.as_str().serialize(serializer)
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize, Alignment> Deserialize<'de> for SpillStr<N, Alignment> {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: serde::Deserializer<'de>,
+  {
+    String::deserialize(deserializer).map(Self::from)
+  }
+}
@@ -0,0 +1,252 @@
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+use std::{string::String, vec::Vec};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::ExceedsCapacity;
+use crate::StrVec;
+use crate::bitmap::Bitmap;
+
+#[cfg(doc)]
+use crate::SpillStrVec28;
+#[cfg(doc)]
+use crate::SpillStrVec56;
+#[cfg(doc)]
+use crate::SpillStrVec112;
+
+/// String vector that spills over to the heap
+///
+/// Items are stored inline in a [StrVec] as long as they fit. Once a push
+/// exceeds the capacity, all items are moved to a heap-allocated `Vec<String>`.
+/// Unlike [StrVec], pushing never fails.
+///
+/// Like [StrVec], an item consisting of a single NUL character is read back as
+/// an empty string, regardless of where the items are stored.
+///
+/// # Usage
+/// ```rust
+/// # use qstr::SpillStrVec28;
+/// let mut vec = SpillStrVec28::new();
+/// vec.push("aws");
+/// vec.push("us");
+/// assert!(vec.is_inline());
+///
+/// vec.push("a rather long region name");
+/// assert!(!vec.is_inline());
+/// assert_eq!(vec.get(1), Some("us"));
+/// ```
+///
+/// # Aliases
+/// The aliases [SpillStrVec28], [SpillStrVec56] and [SpillStrVec112] use the
+/// inline layouts of the corresponding StrVec aliases.
+#[derive(Clone)]
+pub struct SpillStrVec<T: Bitmap, const N: usize, Alignment> {
+  /// Only holds heap items if they exceed the inline capacity
+  repr: Repr<T, N, Alignment>,
+}
+
+#[derive(Clone)]
+enum Repr<T: Bitmap, const N: usize, Alignment> {
+  Inline(StrVec<T, N, Alignment>),
+  Heap(Vec<String>),
+}
+
+impl<T: Bitmap, const N: usize, Alignment> SpillStrVec<T, N, Alignment> {
+  /// Create empty SpillStrVec
+  #[inline]
+  pub fn new() -> Self {
+    Self {
+      repr: Repr::Inline(StrVec::new()),
+    }
+  }
+
+  /// Checks whether the items are stored inline rather than on the heap
+  #[inline]
+  pub fn is_inline(&self) -> bool {
+    matches!(self.repr, Repr::Inline(_))
+  }
+
+  /// Number of items
+  #[inline]
+  pub fn len(&self) -> usize {
+    match &self.repr {
+      Repr::Inline(v) => v.len(),
+      Repr::Heap(v) => v.len(),
+    }
+  }
+
+  /// Checks if there are no elements
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// Inserts given string at the end, moving all items to the heap if it no
+  /// longer fits inline
+  ///
+  /// Note: If s is `"\0"`, it corresponds to an empty string
+  pub fn push(&mut self, s: &str) {
+    match &mut self.repr {
+      Repr::Inline(inline) => {
+        if inline.push(s).is_err() {
+          let mut heap = Vec::with_capacity(inline.len() + 1);
+          heap.extend(inline.iter().map(String::from));
+          heap.push(heap_item(s));
+
+          self.repr = Repr::Heap(heap);
+        }
+      }
+      Repr::Heap(heap) => heap.push(heap_item(s)),
+    }
+  }
+
+  /// Removes all elements. Items are stored inline again afterwards.
+  pub fn clear(&mut self) {
+    *self = Self::new();
+  }
+
+  /// Returns string at given index
+  pub fn get(&self, index: usize) -> Option<&str> {
+    match &self.repr {
+      Repr::Inline(v) => v.get(index),
+      Repr::Heap(v) => v.get(index).map(String::as_str),
+    }
+  }
+
+  /// Convert to an [Iterator]
+  pub fn iter(&self) -> impl Iterator<Item = &str> {
+    let (inline, heap) = match &self.repr {
+      Repr::Inline(v) => (Some(v.iter()), None),
+      Repr::Heap(v) => (None, Some(v.iter().map(String::as_str))),
+    };
+
+    inline
+      .into_iter()
+      .flatten()
+      .chain(heap.into_iter().flatten())
+  }
+
+  /// Convert to a [Vec]
+  pub fn to_vec(&self) -> Vec<&str> {
+    self.iter().collect::<Vec<_>>()
+  }
+}
+
+/// Maps `"\0"` to `""`, matching how [StrVec] reads back such items
+fn heap_item(s: &str) -> String {
+  if s == "\0" {
+    String::new()
+  } else {
+    String::from(s)
+  }
+}
+
+impl<T: Bitmap, const N: usize, Alignment> Default for SpillStrVec<T, N, Alignment> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<T: Bitmap, const N: usize, Alignment> PartialEq for SpillStrVec<T, N, Alignment> {
+  fn eq(&self, other: &Self) -> bool {
+    self.iter().eq(other.iter())
+  }
+}
+
+impl<T: Bitmap, const N: usize, Alignment> Eq for SpillStrVec<T, N, Alignment> {}
+
+impl<T: Bitmap, const N: usize, Alignment> Hash for SpillStrVec<T, N, Alignment> {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    state.write_usize(self.len());
+
+    for item in self.iter() {
+      item.hash(state);
+    }
+  }
+}
+
+impl<T: Bitmap, const N: usize, Alignment> fmt::Debug for SpillStrVec<T, N, Alignment> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_list().entries(self.iter()).finish()
+  }
+}
+
+impl<T: Bitmap, const N: usize, Alignment> From<StrVec<T, N, Alignment>>
+  for SpillStrVec<T, N, Alignment>
+{
+  fn from(v: StrVec<T, N, Alignment>) -> Self {
+    Self {
+      repr: Repr::Inline(v),
+    }
+  }
+}
+
+impl<T: Bitmap, const N: usize, Alignment> From<&[&str]> for SpillStrVec<T, N, Alignment> {
+  fn from(values: &[&str]) -> Self {
+    let mut result = Self::new();
+
+    for v in values {
+      result.push(v);
+    }
+
+    result
+  }
+}
+
+impl<T: Bitmap, const N: usize, Alignment> From<Vec<String>> for SpillStrVec<T, N, Alignment> {
+  /// Reuses the vector if the items do not fit inline
+  fn from(mut values: Vec<String>) -> Self {
+    let repr = match StrVec::try_from(values.as_slice()) {
+      Ok(inline) => Repr::Inline(inline),
+      Err(_) => {
+        for v in values.iter_mut().filter(|v| *v == "\0") {
+          v.clear();
+        }
+
+        Repr::Heap(values)
+      }
+    };
+
+    Self { repr }
+  }
+}
+
+/// Succeeds if the items are stored inline
+impl<T: Bitmap, const N: usize, Alignment> TryFrom<SpillStrVec<T, N, Alignment>>
+  for StrVec<T, N, Alignment>
+{
+  type Error = ExceedsCapacity;
+
+  fn try_from(v: SpillStrVec<T, N, Alignment>) -> Result<Self, Self::Error> {
+    match v.repr {
+      Repr::Inline(inline) => Ok(inline),
+      Repr::Heap(heap) => Err(ExceedsCapacity {
+        length: heap.iter().map(|s| s.len().max(1)).sum(),
+        capacity: N,
+      }),
+    }
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<T: Bitmap, const N: usize, Alignment> Serialize for SpillStrVec<T, N, Alignment> {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    self.to_vec().serialize(serializer)
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Bitmap, const N: usize, Alignment> Deserialize<'de> for SpillStrVec<T, N, Alignment> {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: serde::Deserializer<'de>,
+  {
+    Vec::<String>::deserialize(deserializer).map(Self::from)
+  }
+}
@@ -1,14 +1,33 @@
+use core::cmp::Ordering;
 use core::fmt;
 use core::hash::{self, Hasher};
+use core::iter;
 
 #[cfg(feature = "std")]
 use std::{string::String, vec::Vec};
 
+#[cfg(feature = "schemars")]
+use alloc::{borrow::Cow, format};
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "rkyv")]
+use rkyv::{
+  Archive, Archived, Place, Portable, bytecheck::CheckBytes, munge::munge, rancor::Fallible,
+  traits::NoUndef,
+};
+
+#[cfg(any(feature = "borsh", feature = "bincode"))]
+use crate::BoundedStr;
 use crate::ExceedsCapacity;
+use crate::InvalidBytes;
 use crate::bitmap::Bitmap;
+use crate::ignore_ascii_case::CaseInsensitive;
+#[cfg(any(feature = "zerocopy", feature = "bytemuck"))]
+use crate::raw::RawStrVec;
+use crate::search::{ByteIndices, MatchIndices, Matcher, Native};
+use crate::swar;
 
 #[cfg(doc)]
 use crate::StrVec28;
@@ -79,6 +98,11 @@ use crate::StrVec112;
 /// The following aliases that take into account cache line sizes are available:
 /// [StrVec28], [StrVec56], [StrVec112]
 #[derive(PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(
+  feature = "zerocopy",
+  derive(zerocopy::IntoBytes, zerocopy::KnownLayout, zerocopy::Immutable)
+)]
+#[repr(C)]
 pub struct StrVec<T: Bitmap, const N: usize, Alignment> {
   /// Marks each item's end position with a set bit
   pub(crate) bitmap: T,
@@ -132,6 +156,132 @@ impl<T: Bitmap, const N: usize, Alignment> StrVec<T, N, Alignment> {
     T::BITSIZE - self.bitmap.trailing_zeros()
   }
 
+  /// Checks that `bitmap` and `data` satisfy StrVec's invariants
+  ///
+  /// The bitmap must not mark positions beyond `N`, every item must be valid
+  /// UTF-8 and all unused bytes must be NUL. Offsets are relative to `data`.
+  pub(crate) fn validate(bitmap: T, data: &[u8; N]) -> Result<(), InvalidBytes> {
+    let next_offset = T::BITSIZE - bitmap.trailing_zeros();
+
+    if next_offset > N {
+      return Err(InvalidBytes::InvalidBitmap);
+    }
+
+    let mut offset = 0;
+    let mut bitmap = bitmap;
+
+    for _ in 0..bitmap.count_ones() {
+      let end = bitmap.leading_zeros() + 1;
+
+      if let Err(e) = core::str::from_utf8(&data[offset..end]) {
+        return Err(InvalidBytes::InvalidUtf8 {
+          offset: offset + e.valid_up_to(),
+        });
+      }
+
+      offset = end;
+      bitmap.unset(end - 1);
+    }
+
+    match data[next_offset..].iter().position(|&b| b != 0) {
+      Some(i) => Err(InvalidBytes::NonZeroPadding {
+        offset: next_offset + i,
+      }),
+      None => Ok(()),
+    }
+  }
+
+  /// Casts `bytes` to a StrVec reference without copying
+  ///
+  /// `bytes` must match StrVec's size and alignment, with the bitmap in native
+  /// byte order. The bitmap and items are validated before the reference is
+  /// returned.
+  #[cfg(feature = "zerocopy")]
+  pub fn try_ref_from_bytes(bytes: &[u8]) -> Result<&Self, InvalidBytes>
+  where
+    T: zerocopy::FromBytes + zerocopy::KnownLayout + zerocopy::Immutable,
+    Alignment: zerocopy::FromBytes + zerocopy::KnownLayout + zerocopy::Immutable,
+  {
+    let raw = <RawStrVec<T, N, Alignment> as zerocopy::FromBytes>::ref_from_bytes(bytes)
+      .map_err(|_| InvalidBytes::Layout)?;
+
+    Self::validate(raw.bitmap, &raw.data)?;
+
+    // SAFETY: RawStrVec has the same layout as StrVec, and the invariants were
+    //         checked above
+    Ok(unsafe { &*(raw as *const RawStrVec<T, N, Alignment>).cast::<Self>() })
+  }
+
+  /// Casts `bytes` to a StrVec slice without copying
+  ///
+  /// `bytes` must be aligned and its length must be a multiple of StrVec's
+  /// size. Every item is validated before the slice is returned.
+  #[cfg(feature = "zerocopy")]
+  pub fn try_slice_from_bytes(bytes: &[u8]) -> Result<&[Self], InvalidBytes>
+  where
+    T: zerocopy::FromBytes + zerocopy::KnownLayout + zerocopy::Immutable,
+    Alignment: zerocopy::FromBytes + zerocopy::KnownLayout + zerocopy::Immutable,
+  {
+    let raw = <[RawStrVec<T, N, Alignment>] as zerocopy::FromBytes>::ref_from_bytes(bytes)
+      .map_err(|_| InvalidBytes::Layout)?;
+
+    for item in raw {
+      Self::validate(item.bitmap, &item.data)?;
+    }
+
+    // SAFETY: RawStrVec has the same layout as StrVec, and the invariants of
+    //         every item were checked above
+    Ok(unsafe { core::slice::from_raw_parts(raw.as_ptr().cast::<Self>(), raw.len()) })
+  }
+
+  /// Encodes StrVec in the wire format
+  ///
+  /// The bitmap is written first in little-endian byte order, followed by the
+  /// NUL-padded data. `SIZE` must equal `T::BITSIZE / 8 + N` and is usually
+  /// inferred.
+  pub fn to_bytes<const SIZE: usize>(&self) -> [u8; SIZE] {
+    const {
+      assert!(
+        SIZE == T::BITSIZE / 8 + N,
+        "SIZE must equal T::BITSIZE / 8 + N"
+      )
+    };
+
+    let mut bytes = [0u8; SIZE];
+    let (bitmap, data) = bytes.split_at_mut(T::BITSIZE / 8);
+    self.bitmap.write_le(bitmap);
+    data.copy_from_slice(&self.data);
+    bytes
+  }
+
+  /// Decodes StrVec from the wire format
+  ///
+  /// Returns `Err` if the bitmap marks positions beyond the capacity, an item
+  /// is not valid UTF-8 or the padding contains non-NUL bytes. Offsets are
+  /// relative to `bytes`.
+  pub fn try_from_bytes<const SIZE: usize>(bytes: &[u8; SIZE]) -> Result<Self, InvalidBytes> {
+    const {
+      assert!(
+        SIZE == T::BITSIZE / 8 + N,
+        "SIZE must equal T::BITSIZE / 8 + N"
+      )
+    };
+
+    let (bitmap, rest) = bytes.split_at(T::BITSIZE / 8);
+    let bitmap = T::read_le(bitmap);
+
+    let mut data = [0u8; N];
+    data.copy_from_slice(rest);
+
+    Self::validate(bitmap, &data).map_err(|e| e.offset_by(T::BITSIZE / 8))?;
+
+    Ok(StrVec {
+      bitmap,
+      data,
+      align: [],
+    })
+  }
+
   /// Inserts given string at the end in O(1)
   ///
   /// Note: If s is `"\0"`, it corresponds to an empty string
@@ -154,6 +304,98 @@ impl<T: Bitmap, const N: usize, Alignment> StrVec<T, N, Alignment> {
     Ok(())
   }
 
+  /// Splits `s` by `delimiter`, with items matching those of [str::split]
+  ///
+  /// Delimiters are located several bytes at a time and each item is written
+  /// directly into the buffer. Empty items occupy one byte.
+  ///
+  /// # Usage
+  /// ```rust
+  /// # use qstr::StrVec28;
+  /// let vec = StrVec28::try_from_split("GET /index.html HTTP/1.1", " ").unwrap();
+  /// assert_eq!(vec.get(1), Some("/index.html"));
+  /// assert!(StrVec28::try_from_split(&",".repeat(28), ",").is_err());
+  /// ```
+  pub fn try_from_split(s: &str, delimiter: &str) -> Result<Self, ExceedsCapacity> {
+    Self::split_with::<Native>(s, delimiter)
+  }
+
+  /// Implements [StrVec::try_from_split] with the given search strategy
+  pub(crate) fn split_with<M: Matcher>(s: &str, delimiter: &str) -> Result<Self, ExceedsCapacity> {
+    match delimiter.as_bytes() {
+      // A single-byte delimiter is ASCII, so its matches are character
+      // boundaries
+      &[b] => Self::split_at(s, 1, ByteIndices::<M>::new(s.as_bytes(), b)),
+      _ => Self::split_at(s, delimiter.len(), MatchIndices::<M>::new(s, delimiter)),
+    }
+  }
+
+  /// Constructs a StrVec from the items of `s` between the delimiters
+  /// starting at `matches`
+  fn split_at(
+    s: &str,
+    delimiter_len: usize,
+    matches: impl Iterator<Item = usize>,
+  ) -> Result<Self, ExceedsCapacity> {
+    let mut vec = Self::new();
+    let mut offset = 0;
+    let mut start = 0;
+
+    for end in matches.chain(iter::once(s.len())) {
+      let item = &s.as_bytes()[start..end];
+      let length = item.len().max(1);
+
+      if offset + length > N {
+        return Err(ExceedsCapacity {
+          length: offset + length,
+          capacity: N,
+        });
+      }
+
+      vec.data[offset..offset + item.len()].copy_from_slice(item);
+      offset += length;
+      vec.bitmap.set(offset - 1);
+      start = end + delimiter_len;
+    }
+
+    Ok(vec)
+  }
+
+  /// Constructs a StrVec from items produced by `write_item`, which is given
+  /// the remaining buffer and returns the number of bytes written, or `None`
+  /// to stop. If `fill` is set, the last item is padded to the full capacity.
+  #[cfg(any(feature = "arbitrary", feature = "proptest", feature = "quickcheck"))]
+  pub(crate) fn generate(
+    fill: bool,
+    mut write_item: impl FnMut(&mut [u8]) -> Option<usize>,
+  ) -> Self {
+    let mut vec = Self::new();
+    let mut offset = 0;
+    let mut start = 0;
+
+    while offset < N {
+      let Some(length) = write_item(&mut vec.data[offset..]) else {
+        break;
+      };
+
+      // Empty items occupy a single NUL byte
+      start = offset;
+      offset += length.max(1);
+      vec.bitmap.set(offset - 1);
+    }
+
+    if fill && offset < N {
+      if offset > 0 {
+        vec.bitmap.unset(offset - 1);
+      }
+
+      crate::generate::pad(&mut vec.data, start, offset);
+      vec.bitmap.set(N - 1);
+    }
+
+    vec
+  }
+
   /// Removes all elements
   pub fn clear(&mut self) {
     *self = Self::new();
@@ -162,15 +404,7 @@ impl<T: Bitmap, const N: usize, Alignment> StrVec<T, N, Alignment> {
   /// Returns string at given index in O(N)
   pub fn get(&self, index: usize) -> Option<&str> {
     let (offset, end) = self.bitmap.find_nth_span(index)?;
-    let span = &self.data[offset..end];
-
-    if span == [0] {
-      Some("")
-    } else {
-      // SAFETY: We trust that the stored bytes are valid UTF-8
-      //         since we only store valid strings via push()
-      Some(unsafe { core::str::from_utf8_unchecked(span) })
-    }
+    Some(span_str(&self.data[offset..end]))
   }
 
   /// Checks if there are no elements
@@ -181,24 +415,7 @@ impl<T: Bitmap, const N: usize, Alignment> StrVec<T, N, Alignment> {
 
   /// Convert to an [Iterator]
   pub fn iter(&self) -> impl Iterator<Item = &str> {
-    let mut offset = 0;
-    let mut bitmap = self.bitmap;
-
-    (0..self.len()).map(move |_| {
-      let end = bitmap.leading_zeros();
-      let span = &self.data[offset..(end + 1)];
-
-      offset = end + 1;
-      bitmap.unset(end);
-
-      if span == [0] {
-        ""
-      } else {
-        // SAFETY: We trust that the stored bytes are valid UTF-8
-        //         since we only store valid strings via push()
-        unsafe { core::str::from_utf8_unchecked(span) }
-      }
-    })
+    iter_spans(self.bitmap, &self.data)
   }
 
   /// Convert to a [Vec]
@@ -208,6 +425,34 @@ impl<T: Bitmap, const N: usize, Alignment> StrVec<T, N, Alignment> {
   }
 }
 
+/// Converts an item's span to `&str`, mapping a single NUL byte to `""`
+#[inline]
+fn span_str(span: &[u8]) -> &str {
+  if span == [0] {
+    ""
+  } else {
+    // SAFETY: We trust that the stored bytes are valid UTF-8
+    //         since we only store valid strings via push()
+    unsafe { core::str::from_utf8_unchecked(span) }
+  }
+}
+
+/// Iterates over the items that `bitmap` marks in `data`
+fn iter_spans<T: Bitmap>(bitmap: T, data: &[u8]) -> impl Iterator<Item = &str> {
+  let mut offset = 0;
+  let mut bitmap = bitmap;
+
+  (0..bitmap.count_ones()).map(move |_| {
+    let end = bitmap.leading_zeros();
+    let span = &data[offset..(end + 1)];
+
+    offset = end + 1;
+    bitmap.unset(end);
+
+    span_str(span)
+  })
+}
+
 impl<T: Bitmap, const N: usize, Alignment> Default for StrVec<T, N, Alignment> {
   fn default() -> Self {
     Self::new()
@@ -220,6 +465,37 @@ impl<T: Bitmap, const N: usize, Alignment> hash::Hash for StrVec<T, N, Alignment
   }
 }
 
+impl<T: Bitmap + Eq, const N: usize, Alignment> CaseInsensitive for StrVec<T, N, Alignment> {
+  /// Compares the data buffers eight bytes at a time
+  #[inline]
+  fn case_insensitive_eq(&self, other: &Self) -> bool {
+    self.bitmap == other.bitmap && swar::eq_ignore_ascii_case(&self.data, &other.data)
+  }
+
+  /// Compares the items lexicographically
+  fn case_insensitive_cmp(&self, other: &Self) -> Ordering {
+    let mut a = self.iter();
+    let mut b = other.iter();
+
+    loop {
+      match (a.next(), b.next()) {
+        (Some(a), Some(b)) => match swar::cmp_ignore_ascii_case(a.as_bytes(), b.as_bytes()) {
+          Ordering::Equal => continue,
+          ordering => return ordering,
+        },
+        (Some(_), None) => return Ordering::Greater,
+        (None, Some(_)) => return Ordering::Less,
+        (None, None) => return Ordering::Equal,
+      }
+    }
+  }
+
+  #[inline]
+  fn case_insensitive_hash<H: Hasher>(&self, state: &mut H) {
+    swar::hash_ignore_ascii_case(&self.data, state);
+  }
+}
+
 impl<T: Bitmap + Eq, const N: usize, Alignment: Eq> Ord for StrVec<T, N, Alignment> {
   fn cmp(&self, other: &Self) -> core::cmp::Ordering {
     self.data.cmp(&other.data)
@@ -366,3 +642,542 @@ impl<'de, T: Bitmap, const N: usize, Alignment> Deserialize<'de> for StrVec<T, N
     StrVec::try_from(v).map_err(serde::de::Error::custom)
   }
 }
+
+/// Archived [StrVec]
+///
+/// The layout matches StrVec's in-memory layout, except that the bitmap is
+/// stored as a little-endian integer. Items are read directly from the
+/// archived buffer.
+#[cfg(feature = "rkyv")]
+#[repr(C)]
+pub struct ArchivedStrVec<T: Archive, const N: usize, Alignment> {
+  bitmap: Archived<T>,
+  data: [u8; N],
+  align: [Alignment; 0],
+}
+
+#[cfg(feature = "rkyv")]
+impl<T, const N: usize, Alignment> ArchivedStrVec<T, N, Alignment>
+where
+  T: Bitmap + Archive,
+  Archived<T>: Copy + Into<T>,
+{
+  /// Returns the bitmap in native byte order
+  #[inline]
+  fn bitmap(&self) -> T {
+    self.bitmap.into()
+  }
+
+  /// Number of items in O(1)
+  #[inline]
+  pub fn len(&self) -> usize {
+    self.bitmap().count_ones()
+  }
+
+  /// Checks if there are no elements
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// Returns string at given index in O(N)
+  pub fn get(&self, index: usize) -> Option<&str> {
+    let (offset, end) = self.bitmap().find_nth_span(index)?;
+    Some(span_str(&self.data[offset..end]))
+  }
+
+  /// Convert to an [Iterator]
+  pub fn iter(&self) -> impl Iterator<Item = &str> {
+    iter_spans(self.bitmap(), &self.data)
+  }
+}
+
+#[cfg(feature = "rkyv")]
+impl<T, const N: usize, Alignment> PartialEq<StrVec<T, N, Alignment>>
+  for ArchivedStrVec<T, N, Alignment>
+where
+  T: Bitmap + Archive + PartialEq,
+  Archived<T>: Copy + Into<T>,
+{
+  fn eq(&self, other: &StrVec<T, N, Alignment>) -> bool {
+    self.bitmap() == other.bitmap && self.data == other.data
+  }
+}
+
+#[cfg(feature = "rkyv")]
+impl<T, const N: usize, Alignment> fmt::Debug for ArchivedStrVec<T, N, Alignment>
+where
+  T: Bitmap + Archive,
+  Archived<T>: Copy + Into<T>,
+{
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_list().entries(self.iter()).finish()
+  }
+}
+
+// SAFETY: ArchivedStrVec is `repr(C)`, the archived bitmap is `Portable` and
+//         the alignment is fixed by the `Alignment` marker.
+#[cfg(feature = "rkyv")]
+unsafe impl<T: Archive, const N: usize, Alignment: Portable> Portable
+  for ArchivedStrVec<T, N, Alignment>
+{
+}
+
+#[cfg(feature = "rkyv")]
+impl<T, const N: usize, Alignment> Archive for StrVec<T, N, Alignment>
+where
+  T: Bitmap + Archive,
+  Archived<T>: Copy + From<T> + Into<T> + NoUndef,
+  Alignment: Portable,
+{
+  type Archived = ArchivedStrVec<T, N, Alignment>;
+  type Resolver = ();
+
+  fn resolve(&self, _: Self::Resolver, out: Place<Self::Archived>) {
+    munge!(let ArchivedStrVec { bitmap, data, .. } = out);
+    bitmap.write(self.bitmap.into());
+    data.write(self.data);
+  }
+}
+
+#[cfg(feature = "rkyv")]
+impl<S, T, const N: usize, Alignment> rkyv::Serialize<S> for StrVec<T, N, Alignment>
+where
+  S: Fallible + ?Sized,
+  T: Bitmap + Archive,
+  Archived<T>: Copy + From<T> + Into<T> + NoUndef,
+  Alignment: Portable,
+{
+  fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+    Ok(())
+  }
+}
+
+#[cfg(feature = "rkyv")]
+impl<D, T, const N: usize, Alignment> rkyv::Deserialize<StrVec<T, N, Alignment>, D>
+  for ArchivedStrVec<T, N, Alignment>
+where
+  D: Fallible + ?Sized,
+  T: Bitmap + Archive,
+  Archived<T>: Copy + Into<T>,
+{
+  fn deserialize(&self, _: &mut D) -> Result<StrVec<T, N, Alignment>, D::Error> {
+    Ok(StrVec {
+      bitmap: self.bitmap(),
+      data: self.data,
+      align: [],
+    })
+  }
+}
+
+// SAFETY: The archived bitmap is checked first. Afterwards, every byte pattern
+//         is a valid data buffer and the remaining invariants are checked by
+//         validate().
+#[cfg(feature = "rkyv")]
+unsafe impl<C, T, const N: usize, Alignment> CheckBytes<C> for ArchivedStrVec<T, N, Alignment>
+where
+  C: Fallible + ?Sized,
+  C::Error: rkyv::rancor::Source,
+  T: Bitmap + Archive,
+  Archived<T>: Copy + Into<T> + CheckBytes<C>,
+{
+  unsafe fn check_bytes(value: *const Self, context: &mut C) -> Result<(), C::Error> {
+    unsafe { Archived::<T>::check_bytes(&raw const (*value).bitmap, context)? };
+
+    let value = unsafe { &*value };
+    StrVec::<T, N, Alignment>::validate(value.bitmap(), &value.data)
+      .map_err(rkyv::rancor::Source::new)
+  }
+}
+
+// SAFETY: An all-zero StrVec is empty
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Bitmap + bytemuck::Zeroable, const N: usize, Alignment> bytemuck::Zeroable
+  for StrVec<T, N, Alignment>
+{
+}
+
+// SAFETY: RawStrVec has the same layout as StrVec. validate() checks the bitmap,
+//         UTF-8 validity of every item and NUL padding.
+#[cfg(feature = "bytemuck")]
+unsafe impl<T, const N: usize, Alignment> bytemuck::CheckedBitPattern for StrVec<T, N, Alignment>
+where
+  T: Bitmap + bytemuck::AnyBitPattern,
+  Alignment: Copy + 'static,
+{
+  type Bits = RawStrVec<T, N, Alignment>;
+
+  fn is_valid_bit_pattern(bits: &Self::Bits) -> bool {
+    Self::validate(bits.bitmap, &bits.data).is_ok()
+  }
+}
+
+/// Encodes the items like a `Vec<String>`
+#[cfg(feature = "borsh")]
+impl<T: Bitmap, const N: usize, Alignment> borsh::BorshSerialize for StrVec<T, N, Alignment> {
+  fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+    borsh::BorshSerialize::serialize(&(self.len() as u32), writer)?;
+
+    for item in self.iter() {
+      borsh::BorshSerialize::serialize(item, writer)?;
+    }
+
+    Ok(())
+  }
+}
+
+/// Decodes every item into a stack buffer before appending it
+#[cfg(feature = "borsh")]
+impl<T: Bitmap, const N: usize, Alignment> borsh::BorshDeserialize for StrVec<T, N, Alignment> {
+  fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+    use borsh::io::{Error, ErrorKind};
+
+    let count = <u32 as borsh::BorshDeserialize>::deserialize_reader(reader)?;
+    let mut result = Self::new();
+
+    for _ in 0..count {
+      let item = <BoundedStr<N, ()> as borsh::BorshDeserialize>::deserialize_reader(reader)?;
+
+      result
+        .push(item.as_str())
+        .map_err(|_| Error::new(ErrorKind::InvalidData, "String length exceeds capacity"))?;
+    }
+
+    Ok(result)
+  }
+}
+
+/// Encodes the items like a `Vec<String>`
+#[cfg(feature = "bincode")]
+impl<T: Bitmap, const N: usize, Alignment> bincode::Encode for StrVec<T, N, Alignment> {
+  fn encode<E: bincode::enc::Encoder>(
+    &self,
+    encoder: &mut E,
+  ) -> Result<(), bincode::error::EncodeError> {
+    bincode::Encode::encode(&(self.len() as u64), encoder)?;
+
+    for item in self.iter() {
+      bincode::Encode::encode(item, encoder)?;
+    }
+
+    Ok(())
+  }
+}
+
+/// Decodes every item into a stack buffer before appending it
+#[cfg(feature = "bincode")]
+impl<Context, T: Bitmap, const N: usize, Alignment> bincode::Decode<Context>
+  for StrVec<T, N, Alignment>
+{
+  fn decode<D: bincode::de::Decoder<Context = Context>>(
+    decoder: &mut D,
+  ) -> Result<Self, bincode::error::DecodeError> {
+    use bincode::error::DecodeError;
+
+    let count = <u64 as bincode::Decode<Context>>::decode(decoder)?;
+    let mut result = Self::new();
+
+    for _ in 0..count {
+      let item = <BoundedStr<N, ()> as bincode::Decode<Context>>::decode(decoder)?;
+
+      result
+        .push(item.as_str())
+        .map_err(|_| DecodeError::Other("String length exceeds capacity"))?;
+    }
+
+    Ok(result)
+  }
+}
+
+#[cfg(feature = "bincode")]
+impl<'de, Context, T: Bitmap, const N: usize, Alignment> bincode::BorrowDecode<'de, Context>
+  for StrVec<T, N, Alignment>
+{
+  fn borrow_decode<D: bincode::de::BorrowDecoder<'de, Context = Context>>(
+    decoder: &mut D,
+  ) -> Result<Self, bincode::error::DecodeError> {
+    bincode::Decode::decode(decoder)
+  }
+}
+
+/// Items are encoded as a length-prefixed sequence of length-prefixed strings
+///
+/// Every item occupies at least one byte in StrVec, and its length prefix never
+/// exceeds that footprint. The worst case is therefore `N` single-character
+/// items, which take `2 * N` bytes plus the sequence length.
+#[cfg(feature = "postcard")]
+impl<T: Bitmap, const N: usize, Alignment> postcard::experimental::max_size::MaxSize
+  for StrVec<T, N, Alignment>
+{
+  const POSTCARD_MAX_SIZE: usize = crate::varint_size(N) + 2 * N;
+}
+
+/// An array of at most `N` strings
+///
+/// JSON Schema cannot express the limit on the total length, so it is only
+/// stated in the description: all items combined must fit into `N` UTF-8
+/// bytes, with each empty item taking up one byte.
+#[cfg(feature = "schemars")]
+impl<T: Bitmap, const N: usize, Alignment> schemars::JsonSchema for StrVec<T, N, Alignment> {
+  fn inline_schema() -> bool {
+    true
+  }
+
+  fn schema_name() -> Cow<'static, str> {
+    format!("StrVec{N}").into()
+  }
+
+  fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+    schemars::json_schema!({
+      "type": "array",
+      "items": {
+        "type": "string",
+        "maxLength": N,
+      },
+      "maxItems": N,
+      "description": format!(
+        "Items must not exceed {N} UTF-8 bytes in total, where empty items take up 1 byte"
+      ),
+    })
+  }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T: Bitmap, const N: usize, Alignment> arbitrary::Arbitrary<'a>
+  for StrVec<T, N, Alignment>
+{
+  fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+    let fill = crate::generate::arbitrary_fill(u)?;
+    let count = u.int_in_range(0..=N)?;
+    let mut items = 0;
+
+    Ok(Self::generate(fill, |buf| {
+      items += 1;
+
+      if items > count {
+        return None;
+      }
+
+      let chars = crate::generate::arbitrary_chars(u, crate::generate::MAX_ITEM_CHARS).ok()?;
+      Some(crate::generate::write_chars(buf, chars))
+    }))
+  }
+}
+
+#[cfg(feature = "proptest")]
+impl<T: Bitmap + fmt::Debug + 'static, const N: usize, Alignment: 'static>
+  proptest::arbitrary::Arbitrary for StrVec<T, N, Alignment>
+{
+  type Parameters = ();
+  type Strategy = proptest::strategy::BoxedStrategy<Self>;
+
+  fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+    use proptest::strategy::Strategy;
+
+    let item = proptest::collection::vec(
+      crate::generate::proptest_char(),
+      0..=crate::generate::MAX_ITEM_CHARS,
+    );
+    let items = proptest::collection::vec(item, 0..=N);
+
+    (items, crate::generate::proptest_fill())
+      .prop_map(|(items, fill)| {
+        let mut items = items.into_iter();
+        Self::generate(fill, |buf| {
+          items
+            .next()
+            .map(|item| crate::generate::write_chars(buf, item))
+        })
+      })
+      .boxed()
+  }
+}
+
+#[cfg(feature = "quickcheck")]
+impl<T: Bitmap + 'static, const N: usize, Alignment: Clone + 'static> quickcheck::Arbitrary
+  for StrVec<T, N, Alignment>
+{
+  fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+    let fill = crate::generate::quickcheck_fill(g);
+    let count = crate::generate::quickcheck_count(g, N);
+    let mut items = 0;
+
+    Self::generate(fill, |buf| {
+      items += 1;
+
+      if items > count {
+        return None;
+      }
+
+      let chars = crate::generate::quickcheck_chars(g, crate::generate::MAX_ITEM_CHARS);
+      Some(crate::generate::write_chars(buf, chars))
+    })
+  }
+
+  fn shrink(&self) -> alloc::boxed::Box<dyn Iterator<Item = Self>> {
+    let items: alloc::vec::Vec<alloc::string::String> =
+      self.iter().map(alloc::string::String::from).collect();
+
+    alloc::boxed::Box::new(quickcheck::Arbitrary::shrink(&items).map(|items| {
+      let mut items = items.into_iter();
+      Self::generate(false, |buf| {
+        items
+          .next()
+          .map(|item| crate::generate::write_chars(buf, item.chars()))
+      })
+    }))
+  }
+}
+
+/// Prints the items as a list, like [fmt::Debug]
+#[cfg(feature = "defmt")]
+impl<T: Bitmap, const N: usize, Alignment> defmt::Format for StrVec<T, N, Alignment> {
+  fn format(&self, f: defmt::Formatter) {
+    defmt::write!(f, "[");
+
+    for (i, v) in self.iter().enumerate() {
+      if i > 0 {
+        defmt::write!(f, ", ");
+      }
+
+      defmt::write!(f, "{=str:?}", v);
+    }
+
+    defmt::write!(f, "]");
+  }
+}
+
+/// Prints the items as a list, like [fmt::Debug]
+///
+/// Items are quoted and their characters escaped with [char::escape_debug].
+#[cfg(feature = "ufmt")]
+impl<T: Bitmap, const N: usize, Alignment> ufmt::uDebug for StrVec<T, N, Alignment> {
+  fn fmt<W: ufmt::uWrite + ?Sized>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error> {
+    f.write_str("[")?;
+
+    for (i, v) in self.iter().enumerate() {
+      if i > 0 {
+        f.write_str(", ")?;
+      }
+
+      f.write_char('"')?;
+
+      for c in v.chars() {
+        // Single quotes only need to be escaped in char literals
+        if c == '\'' {
+          f.write_char(c)?;
+        } else {
+          for c in c.escape_debug() {
+            f.write_char(c)?;
+          }
+        }
+      }
+
+      f.write_char('"')?;
+    }
+
+    f.write_str("]")
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<T: Bitmap, const N: usize, const M: usize, Alignment> TryFrom<&[heapless::String<M>]>
+  for StrVec<T, N, Alignment>
+{
+  type Error = ExceedsCapacity;
+
+  /// Attempts to create a StrVec from a `heapless::String` slice
+  fn try_from(values: &[heapless::String<M>]) -> Result<Self, Self::Error> {
+    let mut result = Self::new();
+
+    for v in values {
+      result.push(v)?;
+    }
+
+    Ok(result)
+  }
+}
+
+/// Fallible conversions between any capacities, in place of [TryFrom]
+///
+/// The [From] impls exist for every combination of capacities, so [TryFrom]
+/// impls would conflict with them.
+#[cfg(feature = "heapless")]
+impl<T: Bitmap, const N: usize, Alignment> StrVec<T, N, Alignment> {
+  /// Creates a StrVec from a `heapless::Vec` of any capacity
+  pub fn try_from_heapless<const M: usize, const K: usize>(
+    values: &heapless::Vec<heapless::String<M>, K>,
+  ) -> Result<Self, ExceedsCapacity> {
+    Self::try_from(values.as_slice())
+  }
+
+  /// Converts StrVec into a `heapless::Vec` of any capacity
+  ///
+  /// Fails if there are more than `K` items or an item exceeds `M` bytes.
+  pub fn try_into_heapless<const M: usize, const K: usize>(
+    &self,
+  ) -> Result<heapless::Vec<heapless::String<M>, K>, ExceedsCapacity> {
+    let mut result = heapless::Vec::new();
+
+    for v in self.iter() {
+      let item = heapless::String::try_from(v).map_err(|_| ExceedsCapacity {
+        length: v.len(),
+        capacity: M,
+      })?;
+
+      result.push(item).map_err(|_| ExceedsCapacity {
+        length: self.len(),
+        capacity: K,
+      })?;
+    }
+
+    Ok(result)
+  }
+}
+
+/// Infallible conversion. Requires that `K` items with `M` bytes each fit,
+/// i.e. `K * max(M, 1) <= N`.
+///
+/// The impl exists for all capacities. Others are only rejected once the
+/// conversion is built, e.g. by `cargo build` but not `cargo check`. Use
+/// [StrVec::try_from_heapless] for them instead.
+#[cfg(feature = "heapless")]
+impl<T: Bitmap, const N: usize, const M: usize, const K: usize, Alignment>
+  From<heapless::Vec<heapless::String<M>, K>> for StrVec<T, N, Alignment>
+{
+  fn from(values: heapless::Vec<heapless::String<M>, K>) -> Self {
+    const {
+      assert!(
+        K * if M == 0 { 1 } else { M } <= N,
+        "StrVec capacity must fit K items with M bytes each"
+      )
+    };
+
+    Self::try_from(values.as_slice()).unwrap()
+  }
+}
+
+/// Infallible conversion. Requires `N <= M` and `N <= K`, since a StrVec may
+/// hold up to `N` items or a single item with `N` bytes.
+///
+/// The impl exists for all capacities. Others are only rejected once the
+/// conversion is built, e.g. by `cargo build` but not `cargo check`. Use
+/// [StrVec::try_into_heapless] for them instead.
+#[cfg(feature = "heapless")]
+impl<T: Bitmap, const N: usize, const M: usize, const K: usize, Alignment>
+  From<StrVec<T, N, Alignment>> for heapless::Vec<heapless::String<M>, K>
+{
+  fn from(values: StrVec<T, N, Alignment>) -> Self {
+    const {
+      assert!(
+        N <= M && N <= K,
+        "Target capacity must fit N items with N bytes each"
+      )
+    };
+
+    values
+      .iter()
+      .map(|v| heapless::String::try_from(v).unwrap())
+      .collect()
+  }
+}
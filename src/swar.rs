@@ -0,0 +1,169 @@
+//! SIMD-within-a-register (SWAR) helpers processing eight bytes per step
+
+use core::cmp::Ordering;
+use core::hash::Hasher;
+
+/// Mask of the high bit of each byte
+const HIGH_BITS: u64 = 0x8080_8080_8080_8080;
+
+/// Repeats `b` in each byte of a word
+const fn splat(b: u8) -> u64 {
+  (b as u64) * 0x0101_0101_0101_0101
+}
+
+/// Converts ASCII uppercase letters in `word` to lowercase, leaving all other
+/// bytes unchanged
+#[inline]
+pub(crate) const fn to_ascii_lowercase(word: u64) -> u64 {
+  // Clearing the high bits prevents carries between bytes
+  let low = word & !HIGH_BITS;
+  let ge_a = low + splat(0x80 - b'A');
+  let gt_z = low + splat(0x80 - b'Z' - 1);
+  let upper = ge_a & !gt_z & !word & HIGH_BITS;
+
+  // 0x80 >> 2 is the case bit 0x20
+  word | (upper >> 2)
+}
+
+/// Sets the high bit of each byte in `word` that equals `b`
+///
+/// Unlike the common zero-byte test, the result is exact for every byte, not
+/// only the first match.
+#[cfg_attr(feature = "simd", allow(dead_code))]
+#[inline]
+pub(crate) const fn eq_mask(word: u64, b: u8) -> u64 {
+  let x = word ^ splat(b);
+
+  // Adding 0x7f to the low bits sets the high bit of non-zero bytes without
+  // carrying into the next byte
+  !(((x & !HIGH_BITS) + !HIGH_BITS) | x) & HIGH_BITS
+}
+
+/// Compares two byte strings, ignoring ASCII case
+pub(crate) fn eq_ignore_ascii_case(a: &[u8], b: &[u8]) -> bool {
+  if a.len() != b.len() {
+    return false;
+  }
+
+  let a_words = a.chunks_exact(8);
+  let b_words = b.chunks_exact(8);
+  let rest = a_words
+    .remainder()
+    .eq_ignore_ascii_case(b_words.remainder());
+
+  a_words.zip(b_words).all(|(a, b)| {
+    // chunks_exact() only yields slices of 8 bytes
+    let a = u64::from_le_bytes(a.try_into().unwrap_or_default());
+    let b = u64::from_le_bytes(b.try_into().unwrap_or_default());
+
+    a == b || to_ascii_lowercase(a) == to_ascii_lowercase(b)
+  }) && rest
+}
+
+/// Loads up to eight bytes as a big-endian word, such that comparing words is
+/// equivalent to comparing the bytes lexicographically
+#[inline]
+fn load_be(bytes: &[u8]) -> u64 {
+  let mut word = [0u8; 8];
+  word[..bytes.len()].copy_from_slice(bytes);
+  u64::from_be_bytes(word)
+}
+
+/// Compares two byte strings lexicographically, ignoring ASCII case
+pub(crate) fn cmp_ignore_ascii_case(a: &[u8], b: &[u8]) -> Ordering {
+  let len = a.len().min(b.len());
+
+  for (a, b) in a[..len].chunks(8).zip(b[..len].chunks(8)) {
+    let a = to_ascii_lowercase(load_be(a));
+    let b = to_ascii_lowercase(load_be(b));
+
+    if a != b {
+      return a.cmp(&b);
+    }
+  }
+
+  a.len().cmp(&b.len())
+}
+
+/// Feeds a byte string to `state` as if it was lowercase
+pub(crate) fn hash_ignore_ascii_case<H: Hasher>(bytes: &[u8], state: &mut H) {
+  state.write_usize(bytes.len());
+
+  let words = bytes.chunks_exact(8);
+  let rest = words.remainder();
+
+  for word in words {
+    state.write_u64(to_ascii_lowercase(load_be(word)));
+  }
+
+  for b in rest {
+    state.write_u8(b.to_ascii_lowercase());
+  }
+}
+
+/// Loads eight bytes as a word in native byte order
+#[inline]
+fn load_ne(bytes: &[u8]) -> u64 {
+  // chunks_exact() only yields slices of 8 bytes
+  u64::from_ne_bytes(bytes.try_into().unwrap_or_default())
+}
+
+/// Checks two buffers of the same length for equality a word at a time
+#[inline]
+pub(crate) fn eq_words(a: &[u8], b: &[u8]) -> bool {
+  #[cfg(feature = "simd")]
+  {
+    crate::simd::first_mismatch(a, b).is_none()
+  }
+
+  #[cfg(not(feature = "simd"))]
+  {
+    let a_words = a.chunks_exact(8);
+    let b_words = b.chunks_exact(8);
+
+    a_words.remainder() == b_words.remainder()
+      && a_words.zip(b_words).all(|(a, b)| load_ne(a) == load_ne(b))
+  }
+}
+
+/// Compares two buffers of the same length lexicographically a word at a time
+#[inline]
+pub(crate) fn cmp_words(a: &[u8], b: &[u8]) -> Ordering {
+  #[cfg(feature = "simd")]
+  {
+    match crate::simd::first_mismatch(a, b) {
+      Some(i) => a[i].cmp(&b[i]),
+      None => Ordering::Equal,
+    }
+  }
+
+  #[cfg(not(feature = "simd"))]
+  {
+    let a_words = a.chunks_exact(8);
+    let b_words = b.chunks_exact(8);
+    let rest = a_words.remainder().cmp(b_words.remainder());
+
+    for (a, b) in a_words.zip(b_words) {
+      let (a, b) = (load_be(a), load_be(b));
+
+      if a != b {
+        return a.cmp(&b);
+      }
+    }
+
+    rest
+  }
+}
+
+/// Feeds a buffer to `state` a word at a time
+#[inline]
+pub(crate) fn hash_words<H: Hasher>(bytes: &[u8], state: &mut H) {
+  let words = bytes.chunks_exact(8);
+  let rest = words.remainder();
+
+  for word in words {
+    state.write_u64(load_ne(word));
+  }
+
+  state.write(rest);
+}
@@ -0,0 +1,169 @@
+use crate::swar;
+use crate::{
+  Align8, Align16, AsciiBoundedStr, AsciiFixedStr, BStr15, ExceedsCapacity, FStr8, InvalidBytes,
+};
+
+type ABStr15 = AsciiBoundedStr<15, Align16>;
+type AFStr8 = AsciiFixedStr<8, Align8>;
+
+#[test]
+fn test_layout() {
+  assert_eq!(size_of::<ABStr15>(), size_of::<BStr15>());
+  assert_eq!(align_of::<ABStr15>(), align_of::<BStr15>());
+  assert_eq!(size_of::<AFStr8>(), size_of::<FStr8>());
+  assert_eq!(align_of::<AFStr8>(), align_of::<FStr8>());
+}
+
+#[test]
+fn test_bounded_try_from() {
+  const S: ABStr15 = match ABStr15::try_from("const") {
+    Ok(s) => s,
+    Err(_) => panic!(),
+  };
+  assert_eq!(S.as_str(), "const");
+
+  assert_eq!(
+    ABStr15::try_from("héllo"),
+    Err(InvalidBytes::NonAscii { offset: 1 })
+  );
+  assert_eq!(
+    ABStr15::try_from("abcdefghijklmnop"),
+    Err(InvalidBytes::ExceedsCapacity(ExceedsCapacity {
+      length: 16,
+      capacity: 15,
+    }))
+  );
+
+  let s: ABStr15 = BStr15::from("abc").try_into().unwrap();
+  assert_eq!(BStr15::from(s), BStr15::from("abc"));
+  assert!(TryInto::<ABStr15>::try_into(BStr15::from("ä")).is_err());
+}
+
+#[test]
+fn test_bounded_indexing() {
+  let s = ABStr15::try_from("hello world").unwrap();
+  assert_eq!(s.char_at(0), Some('h'));
+  assert_eq!(s.char_at(10), Some('d'));
+  assert_eq!(s.char_at(11), None);
+
+  assert_eq!(&s[6..], "world");
+  assert_eq!(&s[..5], "hello");
+  assert_eq!(s.get(3..8), Some("lo wo"));
+  assert_eq!(s.get(8..12), None);
+}
+
+#[test]
+#[should_panic]
+fn test_bounded_index_out_of_bounds() {
+  let s = ABStr15::try_from("hello").unwrap();
+  let _ = &s[..6];
+}
+
+#[test]
+fn test_bounded_push() {
+  let mut s = ABStr15::try_from("abc").unwrap();
+  s.push('d').unwrap();
+  s.push_str("efg").unwrap();
+  assert_eq!(s.as_str(), "abcdefg");
+
+  assert_eq!(s.push_str("hé"), Err(InvalidBytes::NonAscii { offset: 8 }));
+  assert_eq!(s.push('€'), Err(InvalidBytes::NonAscii { offset: 7 }));
+  assert!(matches!(
+    s.push_str("hijklmnopq"),
+    Err(InvalidBytes::ExceedsCapacity(_))
+  ));
+  assert_eq!(s.as_str(), "abcdefg");
+}
+
+#[test]
+fn test_bounded_case() {
+  let mut s = ABStr15::try_from("Hello, World!").unwrap();
+  s.make_ascii_uppercase();
+  assert_eq!(s.as_str(), "HELLO, WORLD!");
+  s.make_ascii_lowercase();
+  assert_eq!(s.as_str(), "hello, world!");
+
+  assert!(s.eq_ignore_ascii_case("HeLLo, WoRLD!"));
+  assert!(!s.eq_ignore_ascii_case("HeLLo, WoRLD?"));
+  assert!(!s.eq_ignore_ascii_case("hello"));
+}
+
+#[test]
+fn test_fixed() {
+  let mut s = AFStr8::try_from("GBPUSD").unwrap();
+  assert_eq!(s.len(), 6);
+  assert_eq!(s.trimmed(), "GBPUSD");
+  assert_eq!(s.as_str(), "GBPUSD\0\0");
+  assert_eq!(s.char_at(6), Some('\0'));
+  assert_eq!(s.char_at(8), None);
+  assert_eq!(&s[..3], "GBP");
+
+  s.make_ascii_lowercase();
+  assert_eq!(FStr8::from(s), FStr8::from("gbpusd"));
+  assert!(s.eq_ignore_ascii_case("GBPusd"));
+  assert!(!s.eq_ignore_ascii_case("GBPusd\0\0"));
+
+  assert_eq!(
+    AFStr8::try_from_bytes(b"abc\xffdefg"),
+    Err(InvalidBytes::NonAscii { offset: 3 })
+  );
+  assert!(TryInto::<AFStr8>::try_into(FStr8::from("ü")).is_err());
+}
+
+#[test]
+fn test_swar_lowercase() {
+  for b in 0..=255u8 {
+    let word = u64::from_le_bytes([b, b'A', b, b'z', b, 0x80, b, 0xff]);
+    let expected = u64::from_le_bytes([
+      b.to_ascii_lowercase(),
+      b'a',
+      b.to_ascii_lowercase(),
+      b'z',
+      b.to_ascii_lowercase(),
+      0x80,
+      b.to_ascii_lowercase(),
+      0xff,
+    ]);
+
+    assert_eq!(swar::to_ascii_lowercase(word), expected, "{:#x}", b);
+  }
+}
+
+#[test]
+fn test_swar_eq_ignore_ascii_case() {
+  let a = b"The Quick Brown Fox Jumps";
+  let b = b"tHE qUICK bROWN fOX jUMPS";
+  assert!(swar::eq_ignore_ascii_case(a, b));
+
+  for i in 0..a.len() {
+    let mut c = *b;
+    c[i] ^= 0x01;
+    assert!(!swar::eq_ignore_ascii_case(a, &c), "{}", i);
+  }
+
+  // Non-ASCII bytes that only differ in the case bit are not equal
+  assert!(!swar::eq_ignore_ascii_case(
+    b"\xc1\xc1\xc1\xc1\xc1\xc1\xc1\xc1",
+    b"\xe1\xe1\xe1\xe1\xe1\xe1\xe1\xe1"
+  ));
+  assert!(!swar::eq_ignore_ascii_case(b"ab", b"abc"));
+}
+
+#[cfg(feature = "serde")]
+mod serde_tests {
+  use super::*;
+
+  #[test]
+  fn test_serde() {
+    let s = ABStr15::try_from("abc").unwrap();
+    let json = serde_json::to_string(&s).unwrap();
+    assert_eq!(json, "\"abc\"");
+    assert_eq!(serde_json::from_str::<ABStr15>(&json).unwrap(), s);
+    assert!(serde_json::from_str::<ABStr15>("\"äbc\"").is_err());
+
+    let s = AFStr8::try_from("abc").unwrap();
+    let json = serde_json::to_string(&s).unwrap();
+    assert_eq!(serde_json::from_str::<AFStr8>(&json).unwrap(), s);
+    assert!(serde_json::from_str::<AFStr8>("\"äbc\"").is_err());
+  }
+}
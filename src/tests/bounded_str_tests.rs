@@ -6,6 +6,7 @@ use crate::BStr31;
 use crate::BStr63;
 use crate::BStr127;
 use crate::ExceedsCapacity;
+use crate::InvalidBytes;
 
 #[test]
 fn test_size() {
@@ -82,6 +83,36 @@ fn test_into() {
   let _v: BStr7 = "asdf".into();
 }
 
+#[test]
+fn test_wire_format_golden() {
+  let golden = include_bytes!("golden/bstr15_v1.bin");
+  let s = BStr15::from("aws:us:east:1");
+
+  assert_eq!(&s.to_bytes(), golden);
+  assert_eq!(BStr15::try_from_bytes(golden), Ok(s));
+}
+
+#[test]
+fn test_try_from_bytes_invalid() {
+  assert_eq!(
+    BStr7::try_from_bytes(b"\x08abcdefg"),
+    Err(InvalidBytes::ExceedsCapacity(ExceedsCapacity {
+      length: 8,
+      capacity: 7
+    }))
+  );
+
+  assert_eq!(
+    BStr7::try_from_bytes(b"\x03ab\xff\0\0\0\0"),
+    Err(InvalidBytes::InvalidUtf8 { offset: 3 })
+  );
+
+  assert_eq!(
+    BStr7::try_from_bytes(b"\x02ab\0\0x\0\0"),
+    Err(InvalidBytes::NonZeroPadding { offset: 5 })
+  );
+}
+
 #[cfg(feature = "std")]
 mod std {
   use std::format;
@@ -122,6 +153,27 @@ mod std {
     );
   }
 
+  #[test]
+  fn test_split_delimiter_at_capacity() {
+    let v: BStr7 = "abcdefg".into();
+    assert_eq!(v.split("gh").iter().collect::<Vec<_>>(), vec!["abcdefg"]);
+  }
+
+  #[test]
+  fn test_split_delimiter_in_padding() {
+    let v: BStr7 = "ab".into();
+    assert_eq!(v.split("b\0").iter().collect::<Vec<_>>(), vec!["ab"]);
+  }
+
+  #[test]
+  fn test_split_empty_delimiter() {
+    let v: BStr7 = "ab".into();
+    assert_eq!(
+      v.split("").iter().collect::<Vec<_>>(),
+      vec!["", "a", "b", ""]
+    );
+  }
+
   #[test]
   fn test_split3() {
     let v: BStr63 = "us:aws:east:1:worker0000000000000000000000000000000000000000000".into();
@@ -186,3 +238,598 @@ mod serde_tests {
     );
   }
 }
+
+#[cfg(all(feature = "rkyv", feature = "std"))]
+mod rkyv_tests {
+  use rkyv::rancor::Error;
+  use rkyv::util::AlignedVec;
+
+  use crate::{BStr7, BStr31};
+
+  #[test]
+  fn test_archive_layout() {
+    let s = BStr7::from("abc");
+    let bytes = rkyv::api::high::to_bytes_in::<_, Error>(&s, AlignedVec::<8>::new()).unwrap();
+
+    assert_eq!(&bytes[..], b"\x03abc\0\0\0\0");
+  }
+
+  #[test]
+  fn test_access() {
+    let s = BStr31::from("tenant-0001");
+    let bytes = rkyv::api::high::to_bytes_in::<_, Error>(&s, AlignedVec::<32>::new()).unwrap();
+
+    let archived = rkyv::access::<BStr31, Error>(&bytes).unwrap();
+    assert_eq!(archived, &s);
+    assert_eq!(archived.as_str(), "tenant-0001");
+
+    let deserialised = rkyv::deserialize::<BStr31, Error>(archived).unwrap();
+    assert_eq!(deserialised, s);
+  }
+
+  #[test]
+  fn test_access_invalid() {
+    let mut bytes = AlignedVec::<8>::new();
+
+    // Length exceeds capacity
+    bytes.extend_from_slice(b"\x08abcdefg");
+    assert!(rkyv::access::<BStr7, Error>(&bytes).is_err());
+
+    // Invalid UTF-8
+    bytes.clear();
+    bytes.extend_from_slice(b"\x02\xc3\x28\0\0\0\0\0");
+    assert!(rkyv::access::<BStr7, Error>(&bytes).is_err());
+
+    // Non-zero padding
+    bytes.clear();
+    bytes.extend_from_slice(b"\x02ab\0\0\0x");
+    assert!(rkyv::access::<BStr7, Error>(&bytes).is_err());
+  }
+}
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck_tests {
+  use crate::{BStr7, BStr15};
+
+  #[test]
+  fn test_layout() {
+    let s = BStr7::from("abc");
+    assert_eq!(bytemuck::bytes_of(&s), b"\x03abc\0\0\0\0");
+  }
+
+  #[test]
+  fn test_try_from_bytes() {
+    #[repr(C, align(16))]
+    struct Buffer([u8; 32]);
+
+    let buffer = Buffer(*b"\x05hello\0\0\0\0\0\0\0\0\0\0\x02ab\0\0\0\0\0\0\0\0\0\0\0\0\0");
+
+    let items = bytemuck::checked::try_cast_slice::<u8, BStr15>(&buffer.0).unwrap();
+    assert_eq!(items, [BStr15::from("hello"), BStr15::from("ab")]);
+  }
+
+  #[test]
+  fn test_try_from_bytes_invalid() {
+    #[repr(C, align(8))]
+    struct Buffer([u8; 8]);
+
+    // Length exceeds capacity
+    let buffer = Buffer(*b"\x08abcdefg");
+    assert!(bytemuck::checked::try_from_bytes::<BStr7>(&buffer.0).is_err());
+
+    // Non-zero padding
+    let buffer = Buffer(*b"\x01ab\0\0\0\0\0");
+    assert!(bytemuck::checked::try_from_bytes::<BStr7>(&buffer.0).is_err());
+  }
+}
+
+#[cfg(feature = "zerocopy")]
+mod zerocopy_tests {
+  use zerocopy::IntoBytes;
+
+  use crate::{BStr7, BStr15, BoundedStr, InvalidBytes};
+
+  #[test]
+  fn test_layout() {
+    // IntoBytes is derived for unaligned strings, which have no padding
+    let s = BoundedStr::<7, ()>::try_from("abc").unwrap();
+    assert_eq!(s.as_bytes(), b"\x03abc\0\0\0\0");
+
+    let s = BStr7::from("abc");
+    assert_eq!(s.as_raw_bytes(), b"\x03abc\0\0\0\0");
+  }
+
+  #[test]
+  fn test_try_slice_from_bytes() {
+    #[repr(C, align(16))]
+    struct Buffer([u8; 32]);
+
+    let buffer = Buffer(*b"\x05hello\0\0\0\0\0\0\0\0\0\0\x02ab\0\0\0\0\0\0\0\0\0\0\0\0\0");
+
+    let items = BStr15::try_slice_from_bytes(&buffer.0).unwrap();
+    assert_eq!(items, [BStr15::from("hello"), BStr15::from("ab")]);
+  }
+
+  #[test]
+  fn test_try_ref_from_bytes_invalid() {
+    #[repr(C, align(8))]
+    struct Buffer([u8; 16]);
+
+    let buffer = Buffer(*b"\x02\xc3\x28\0\0\0\0\0\0\0\0\0\0\0\0\0");
+
+    assert_eq!(
+      BStr7::try_ref_from_bytes(&buffer.0[..8]),
+      Err(InvalidBytes::InvalidUtf8 { offset: 0 })
+    );
+
+    // Misaligned
+    assert_eq!(
+      BStr7::try_ref_from_bytes(&buffer.0[1..9]),
+      Err(InvalidBytes::Layout)
+    );
+  }
+}
+
+#[cfg(all(feature = "borsh", feature = "std"))]
+mod borsh_tests {
+  use std::string::String;
+
+  use crate::{BStr7, BStr15};
+
+  #[test]
+  fn test_round_trip() {
+    let s = BStr15::from("aws:us:east:1");
+    let bytes = borsh::to_vec(&s).unwrap();
+
+    assert_eq!(
+      bytes,
+      borsh::to_vec(&String::from("aws:us:east:1")).unwrap()
+    );
+    assert_eq!(borsh::from_slice::<BStr15>(&bytes).unwrap(), s);
+  }
+
+  #[test]
+  fn test_exceeds_capacity() {
+    let bytes = borsh::to_vec(&String::from("abcdefgh")).unwrap();
+    assert!(borsh::from_slice::<BStr7>(&bytes).is_err());
+  }
+
+  #[test]
+  fn test_invalid_utf8() {
+    let bytes = borsh::to_vec(&[0xc3u8, 0x28][..]).unwrap();
+    assert!(borsh::from_slice::<BStr7>(&bytes).is_err());
+  }
+}
+
+#[cfg(all(feature = "bincode", feature = "std"))]
+mod bincode_tests {
+  use std::string::String;
+
+  use crate::{BStr7, BStr15};
+
+  #[test]
+  fn test_round_trip() {
+    let config = bincode::config::standard();
+    let s = BStr15::from("aws:us:east:1");
+    let bytes = bincode::encode_to_vec(s, config).unwrap();
+
+    assert_eq!(
+      bytes,
+      bincode::encode_to_vec(String::from("aws:us:east:1"), config).unwrap()
+    );
+    assert_eq!(
+      bincode::decode_from_slice::<BStr15, _>(&bytes, config).unwrap(),
+      (s, bytes.len())
+    );
+  }
+
+  #[test]
+  fn test_exceeds_capacity() {
+    let config = bincode::config::standard();
+    let bytes = bincode::encode_to_vec(String::from("abcdefgh"), config).unwrap();
+
+    assert!(bincode::decode_from_slice::<BStr7, _>(&bytes, config).is_err());
+  }
+}
+
+#[cfg(feature = "postcard")]
+mod postcard_tests {
+  use postcard::experimental::max_size::MaxSize;
+
+  use crate::{Align8, BStr7, BStr127, BoundedStr};
+
+  #[test]
+  fn test_max_size() {
+    assert_eq!(BStr7::POSTCARD_MAX_SIZE, 1 + 7);
+    assert_eq!(BStr127::POSTCARD_MAX_SIZE, 1 + 127);
+    assert_eq!(BoundedStr::<200, Align8>::POSTCARD_MAX_SIZE, 2 + 200);
+  }
+
+  #[cfg(feature = "serde")]
+  #[test]
+  fn test_max_size_worst_case() {
+    let mut buf = [0u8; BStr127::POSTCARD_MAX_SIZE];
+    let s = BStr127::try_from("é".repeat(63).as_str()).unwrap();
+
+    let encoded = postcard::to_slice(&s, &mut buf).unwrap();
+    assert_eq!(encoded.len(), BStr127::POSTCARD_MAX_SIZE - 1);
+
+    let mut buf = [0u8; BoundedStr::<200, Align8>::POSTCARD_MAX_SIZE];
+    let s = BoundedStr::<200, Align8>::try_from("a".repeat(200).as_str()).unwrap();
+
+    let encoded = postcard::to_slice(&s, &mut buf).unwrap();
+    assert_eq!(encoded.len(), BoundedStr::<200, Align8>::POSTCARD_MAX_SIZE);
+  }
+}
+
+#[cfg(feature = "schemars")]
+mod schemars_tests {
+  use schemars::{JsonSchema, SchemaGenerator, json_schema};
+
+  use crate::{BStr31, BoundedStr};
+
+  #[test]
+  fn test_schema() {
+    let schema = BStr31::json_schema(&mut SchemaGenerator::default());
+    assert_eq!(schema, json_schema!({ "type": "string", "maxLength": 31 }));
+
+    let schema = BoundedStr::<5, ()>::json_schema(&mut SchemaGenerator::default());
+    assert_eq!(schema, json_schema!({ "type": "string", "maxLength": 5 }));
+  }
+
+  #[test]
+  fn test_schema_inlined() {
+    let mut generator = SchemaGenerator::default();
+
+    let schema = generator.subschema_for::<BStr31>();
+    assert_eq!(schema.get("maxLength").unwrap(), 31);
+
+    let schema = generator.subschema_for::<BoundedStr<5, ()>>();
+    assert_eq!(schema.get("maxLength").unwrap(), 5);
+
+    assert!(generator.definitions().is_empty());
+  }
+}
+
+#[cfg(all(feature = "proptest", feature = "std"))]
+mod proptest_tests {
+  use std::vec::Vec;
+
+  use proptest::prelude::*;
+  use proptest::strategy::ValueTree;
+  use proptest::test_runner::TestRunner;
+
+  use crate::{Align8, BStr15, BoundedStr};
+
+  proptest! {
+    #[test]
+    fn test_wire_format_roundtrip(s: BStr15) {
+      let bytes: [u8; 16] = s.to_bytes();
+      prop_assert_eq!(BStr15::try_from_bytes(&bytes), Ok(s));
+    }
+
+    #[test]
+    fn test_str_roundtrip(s: BoundedStr<5, Align8>) {
+      prop_assert!(s.len() <= 5);
+      prop_assert_eq!(BoundedStr::<5, Align8>::try_from(s.as_str()), Ok(s));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_roundtrip(s: BStr15) {
+      let json = serde_json::to_string(&s).unwrap();
+      prop_assert_eq!(serde_json::from_str::<BStr15>(&json).unwrap(), s);
+    }
+  }
+
+  #[test]
+  fn test_edge_cases() {
+    let mut runner = TestRunner::deterministic();
+    let strategy = any::<BStr15>();
+
+    let values: Vec<BStr15> = (0..1000)
+      .map(|_| strategy.new_tree(&mut runner).unwrap().current())
+      .collect();
+
+    assert!(values.iter().any(|s| s.is_empty()));
+    assert!(values.iter().any(|s| s.as_str().contains('\0')));
+    assert!(values.iter().any(|s| s.len() == 15));
+    assert!(
+      values
+        .iter()
+        .any(|s| s.len() == 15 && s.as_str().chars().last().unwrap().len_utf8() > 1)
+    );
+  }
+}
+
+#[cfg(all(feature = "quickcheck", feature = "std"))]
+mod quickcheck_tests {
+  use quickcheck::{Arbitrary, Gen, quickcheck};
+
+  use crate::BStr15;
+
+  #[test]
+  fn test_wire_format_roundtrip() {
+    fn prop(s: BStr15) -> bool {
+      let bytes: [u8; 16] = s.to_bytes();
+      BStr15::try_from_bytes(&bytes) == Ok(s)
+    }
+
+    quickcheck(prop as fn(BStr15) -> bool);
+  }
+
+  #[test]
+  fn test_shrink() {
+    let mut g = Gen::new(100);
+
+    for _ in 0..100 {
+      let s = BStr15::arbitrary(&mut g);
+
+      for shrunk in s.shrink() {
+        assert!(shrunk.len() <= s.len());
+      }
+    }
+  }
+}
+
+#[cfg(all(feature = "arbitrary", feature = "std"))]
+mod arbitrary_tests {
+  use std::vec::Vec;
+
+  use arbitrary::{Arbitrary, Unstructured};
+
+  use crate::BStr15;
+
+  #[test]
+  fn test_wire_format_roundtrip() {
+    let data: Vec<u8> = (0..4096u32)
+      .map(|i| (i.wrapping_mul(2654435761) >> 13) as u8)
+      .collect();
+    let mut u = Unstructured::new(&data);
+    let mut full = false;
+
+    while !u.is_empty() {
+      let s = BStr15::arbitrary(&mut u).unwrap();
+      full |= s.len() == 15;
+
+      let bytes: [u8; 16] = s.to_bytes();
+      assert_eq!(BStr15::try_from_bytes(&bytes), Ok(s));
+    }
+
+    assert!(full);
+  }
+
+  #[test]
+  fn test_exhausted_input() {
+    let mut u = Unstructured::new(&[]);
+    assert_eq!(BStr15::arbitrary(&mut u).unwrap(), BStr15::new());
+  }
+}
+
+#[cfg(feature = "ufmt")]
+mod ufmt_tests {
+  use ufmt::uwrite;
+
+  use crate::{BStr7, BStr15, ExceedsCapacity};
+
+  #[test]
+  fn test_display() {
+    let s = BStr15::from("device-é");
+    let mut out = BStr15::new();
+
+    uwrite!(out, "{}", s).unwrap();
+    assert_eq!(out, s);
+  }
+
+  #[test]
+  fn test_debug() {
+    let mut out = BStr15::new();
+
+    uwrite!(out, "{:?}", BStr7::from("abc")).unwrap();
+    assert_eq!(out.as_str(), "abc");
+  }
+
+  #[test]
+  fn test_write() {
+    let mut out = BStr15::new();
+
+    uwrite!(out, "{}-{}", "sensor", 42u8).unwrap();
+    assert_eq!(out.as_str(), "sensor-42");
+  }
+
+  #[test]
+  fn test_write_exceeds_capacity() {
+    let mut out = BStr7::new();
+
+    assert_eq!(
+      uwrite!(out, "{}{}", "abcd", "efgh"),
+      Err(ExceedsCapacity {
+        length: 8,
+        capacity: 7
+      })
+    );
+    assert_eq!(out.as_str(), "abcd");
+  }
+}
+
+#[cfg(feature = "defmt")]
+mod defmt_tests {
+  use crate::{Align8, BStr15, BoundedStr};
+
+  fn assert_format<T: defmt::Format>() {}
+
+  #[test]
+  fn test_format() {
+    assert_format::<BStr15>();
+    assert_format::<BoundedStr<3, Align8>>();
+  }
+}
+
+#[cfg(feature = "heapless")]
+mod heapless_tests {
+  use crate::{BStr7, BStr15, ExceedsCapacity};
+
+  #[test]
+  fn test_from_heapless() {
+    let s: heapless::String<7> = heapless::String::try_from("abcdefg").unwrap();
+
+    assert_eq!(BStr7::from(s.clone()), BStr7::from("abcdefg"));
+    assert_eq!(BStr15::from(s), BStr15::from("abcdefg"));
+  }
+
+  #[test]
+  fn test_into_heapless() {
+    let s: heapless::String<16> = BStr15::from("device-é").into();
+    assert_eq!(s.as_str(), "device-é");
+  }
+
+  #[test]
+  fn test_try_from_larger_heapless() {
+    let s: heapless::String<16> = heapless::String::try_from("abcdefgh").unwrap();
+
+    assert_eq!(
+      BStr7::try_from_heapless(&s),
+      Err(ExceedsCapacity {
+        length: 8,
+        capacity: 7
+      })
+    );
+    assert_eq!(BStr15::try_from_heapless(&s), Ok(BStr15::from("abcdefgh")));
+  }
+
+  #[test]
+  fn test_try_into_smaller_heapless() {
+    let s = BStr15::from("abcdefgh");
+
+    assert_eq!(s.try_into_heapless::<8>().unwrap().as_str(), "abcdefgh");
+    assert_eq!(
+      s.try_into_heapless::<7>(),
+      Err(ExceedsCapacity {
+        length: 8,
+        capacity: 7
+      })
+    );
+  }
+}
+
+#[cfg(feature = "arrayvec")]
+mod arrayvec_tests {
+  use crate::{BStr7, BStr15, ExceedsCapacity};
+
+  #[test]
+  fn test_from_arrayvec() {
+    let s = arrayvec::ArrayString::<7>::from("abcdefg").unwrap();
+    assert_eq!(BStr15::from(s), BStr15::from("abcdefg"));
+  }
+
+  #[test]
+  fn test_into_arrayvec() {
+    let s: arrayvec::ArrayString<7> = BStr7::from("abc").into();
+    assert_eq!(s.as_str(), "abc");
+  }
+
+  #[test]
+  fn test_try_arrayvec() {
+    let s = arrayvec::ArrayString::<16>::from("abcdefgh").unwrap();
+    assert!(BStr7::try_from_arrayvec(&s).is_err());
+
+    let s = BStr15::try_from_arrayvec(&s).unwrap();
+    assert_eq!(s.try_into_arrayvec::<8>().unwrap().as_str(), "abcdefgh");
+    assert_eq!(
+      s.try_into_arrayvec::<7>(),
+      Err(ExceedsCapacity {
+        length: 8,
+        capacity: 7
+      })
+    );
+  }
+}
+
+#[cfg(feature = "smol_str")]
+mod smol_str_tests {
+  use smol_str::SmolStr;
+
+  use crate::{BStr7, BStr63, ExceedsCapacity};
+
+  #[test]
+  fn test_into_smol_str() {
+    let s = SmolStr::from(BStr63::from("device-é"));
+
+    assert_eq!(s, "device-é");
+    assert!(!s.is_heap_allocated());
+  }
+
+  #[test]
+  fn test_try_from_smol_str() {
+    assert_eq!(
+      BStr7::try_from(&SmolStr::new("abc")),
+      Ok(BStr7::from("abc"))
+    );
+    assert_eq!(
+      BStr7::try_from(&SmolStr::new("abcdefgh")),
+      Err(ExceedsCapacity {
+        length: 8,
+        capacity: 7
+      })
+    );
+  }
+
+  #[test]
+  fn test_eq() {
+    assert_eq!(BStr7::from("abc"), SmolStr::new("abc"));
+    assert_eq!(SmolStr::new("abc"), BStr7::from("abc"));
+    assert_ne!(BStr7::from("abc"), SmolStr::new("abd"));
+  }
+}
+
+#[cfg(feature = "compact_str")]
+mod compact_str_tests {
+  use compact_str::CompactString;
+
+  use crate::{BStr7, BStr15};
+
+  #[test]
+  fn test_into_compact_str() {
+    let s = CompactString::from(BStr15::from("device-é"));
+
+    assert_eq!(s, "device-é");
+    assert!(!s.is_heap_allocated());
+  }
+
+  #[test]
+  fn test_try_from_compact_str() {
+    let s = CompactString::new("abc");
+    assert_eq!(BStr7::try_from(&s), Ok(BStr7::from("abc")));
+  }
+
+  #[test]
+  fn test_eq() {
+    assert_eq!(BStr7::from("abc"), CompactString::new("abc"));
+    assert_eq!(CompactString::new("abc"), BStr7::from("abc"));
+  }
+}
+
+#[cfg(feature = "bstr")]
+mod bstr_tests {
+  use bstr::{BStr, ByteSlice};
+
+  use crate::BStr15;
+
+  #[test]
+  fn test_as_bstr() {
+    let s = BStr15::from("us:east:1");
+
+    assert_eq!(s.as_bstr(), "us:east:1");
+    assert_eq!(s.as_bstr().find_byte(b':'), Some(2));
+    assert_eq!(
+      AsRef::<BStr>::as_ref(&s).fields_with(|c| c == ':').count(),
+      3
+    );
+  }
+
+  #[test]
+  fn test_eq() {
+    assert_eq!(BStr15::from("abc"), *BStr::new("abc"));
+    assert_eq!(*BStr::new("abc"), BStr15::from("abc"));
+  }
+}
@@ -0,0 +1,213 @@
+use crate::{
+  Align8, Align16, DecodeError, FStr8, FStr16, FStr32, FStr64, FixedStr, Nul, Pad, Padding,
+  RightSpace, RightZero, Space,
+};
+
+/// Round-trips bytes whose encoding starts or ends with characters that may
+/// equal the padding byte through every codec
+fn check_padding<P: Padding>() {
+  for bytes in [[0u8; 5], [0, 1, 0, 0, 0], [0, 0, 0, 0, 1], [0xff; 5]] {
+    let s = FixedStr::<16, Align16, P>::encode_hex(&bytes);
+    assert_eq!(s.decode_hex(), Ok(bytes), "{:?}", s);
+
+    let s = FixedStr::<16, Align16, P>::encode_base32(&bytes);
+    assert_eq!(s.decode_base32(), Ok(bytes), "{:?}", s);
+
+    let s = FixedStr::<16, Align16, P>::encode_base64(&bytes);
+    assert_eq!(s.decode_base64(), Ok(bytes), "{:?}", s);
+
+    let s = FixedStr::<16, Align16, P>::encode_base64_url(&bytes);
+    assert_eq!(s.decode_base64_url(), Ok(bytes), "{:?}", s);
+  }
+}
+
+#[test]
+fn test_padding_round_trip() {
+  check_padding::<Nul>();
+  check_padding::<Space>();
+  check_padding::<RightSpace>();
+  check_padding::<RightZero>();
+  check_padding::<Pad<b'A'>>();
+  check_padding::<Pad<b'A', true>>();
+  check_padding::<Pad<b'='>>();
+  check_padding::<Pad<b'=', true>>();
+}
+
+#[test]
+fn test_right_zero_hex() {
+  let s = FixedStr::<4, Align8, RightZero>::encode_hex(&[0x00, 0x01]);
+  assert_eq!(s.as_str(), "0001");
+  assert_eq!(s.decode_hex(), Ok([0x00, 0x01]));
+
+  // Leading zeros beyond the encoded length are padding
+  let s = FixedStr::<8, Align8, RightZero>::from("123");
+  assert_eq!(s.decode_hex(), Ok([0x01, 0x23]));
+
+  let s = FixedStr::<8, Align8, RightZero>::from("12345");
+  assert_eq!(
+    s.decode_hex::<2>(),
+    Err(DecodeError::InvalidLength {
+      length: 5,
+      expected: 4
+    })
+  );
+}
+
+#[test]
+fn test_hex() {
+  let digest = [0xabu8; 32];
+  let s = FStr64::encode_hex(&digest);
+
+  assert_eq!(s.as_str(), "ab".repeat(32).as_str());
+  assert_eq!(s.decode_hex(), Ok(digest));
+
+  assert_eq!(
+    FStr8::from("DEADbeef").decode_hex(),
+    Ok([0xde, 0xad, 0xbe, 0xef])
+  );
+}
+
+#[test]
+fn test_hex_shorter_than_capacity() {
+  let s = FStr16::encode_hex(&[0x01, 0x23]);
+  assert_eq!(s.as_str_trimmed(), "0123");
+  assert_eq!(s.decode_hex(), Ok([0x01, 0x23]));
+
+  let s = FixedStr::<8, Align8, RightSpace>::encode_hex(&[0xff]);
+  assert_eq!(s.as_str(), "      ff");
+  assert_eq!(s.decode_hex(), Ok([0xff]));
+}
+
+#[test]
+fn test_hex_errors() {
+  assert_eq!(
+    FStr8::from("abc").decode_hex::<2>(),
+    Err(DecodeError::InvalidLength {
+      length: 3,
+      expected: 4
+    })
+  );
+  assert_eq!(
+    FStr8::from("ab0g").decode_hex::<2>(),
+    Err(DecodeError::InvalidCharacter { offset: 3 })
+  );
+}
+
+#[test]
+fn test_base64_rfc4648() {
+  assert_eq!(FStr8::encode_base64(b"").as_str_trimmed(), "");
+  assert_eq!(FStr8::encode_base64(b"f").as_str_trimmed(), "Zg==");
+  assert_eq!(FStr8::encode_base64(b"fo").as_str_trimmed(), "Zm8=");
+  assert_eq!(FStr8::encode_base64(b"foo").as_str_trimmed(), "Zm9v");
+  assert_eq!(FStr8::encode_base64(b"foob").as_str_trimmed(), "Zm9vYg==");
+  assert_eq!(FStr8::encode_base64(b"fooba").as_str_trimmed(), "Zm9vYmE=");
+  assert_eq!(FStr8::encode_base64(b"foobar").as_str_trimmed(), "Zm9vYmFy");
+
+  assert_eq!(FStr8::from("Zm9vYg==").decode_base64(), Ok(*b"foob"));
+  assert_eq!(FStr8::from("Zm9vYmE=").decode_base64(), Ok(*b"fooba"));
+}
+
+#[test]
+fn test_base64_url() {
+  let bytes = [0xfb, 0xff, 0xbf];
+  assert_eq!(FStr8::encode_base64(&bytes).as_str_trimmed(), "+/+/");
+  assert_eq!(FStr8::encode_base64_url(&bytes).as_str_trimmed(), "-_-_");
+
+  let s = FStr8::encode_base64_url(b"fo");
+  assert_eq!(s.as_str_trimmed(), "Zm8");
+  assert_eq!(s.decode_base64_url(), Ok(*b"fo"));
+
+  let token = [0x5au8; 24];
+  let s = FStr32::encode_base64_url(&token);
+  assert_eq!(s.decode_base64_url(), Ok(token));
+}
+
+#[test]
+fn test_base64_errors() {
+  assert_eq!(
+    FStr8::from("Zm8").decode_base64::<2>(),
+    Err(DecodeError::InvalidLength {
+      length: 3,
+      expected: 4
+    })
+  );
+  assert_eq!(
+    FStr8::from("Zm8A").decode_base64::<2>(),
+    Err(DecodeError::InvalidPadding { offset: 3 })
+  );
+  assert_eq!(
+    FStr8::from("Zm-=").decode_base64::<2>(),
+    Err(DecodeError::InvalidCharacter { offset: 2 })
+  );
+
+  // Unused bits of the last character must be zero
+  assert_eq!(
+    FStr8::from("Zm9=").decode_base64::<2>(),
+    Err(DecodeError::InvalidCharacter { offset: 2 })
+  );
+}
+
+#[test]
+fn test_base32_rfc4648() {
+  assert_eq!(FStr16::encode_base32(b"").as_str_trimmed(), "");
+  assert_eq!(FStr16::encode_base32(b"f").as_str_trimmed(), "MY======");
+  assert_eq!(FStr16::encode_base32(b"fo").as_str_trimmed(), "MZXQ====");
+  assert_eq!(FStr16::encode_base32(b"foo").as_str_trimmed(), "MZXW6===");
+  assert_eq!(FStr16::encode_base32(b"foob").as_str_trimmed(), "MZXW6YQ=");
+  assert_eq!(FStr16::encode_base32(b"fooba").as_str_trimmed(), "MZXW6YTB");
+  assert_eq!(
+    FStr16::encode_base32(b"foobar").as_str(),
+    "MZXW6YTBOI======"
+  );
+
+  assert_eq!(
+    FStr16::from("MZXW6YTBOI======").decode_base32(),
+    Ok(*b"foobar")
+  );
+  assert_eq!(FStr16::from("MZXW6YQ=").decode_base32(), Ok(*b"foob"));
+}
+
+#[test]
+fn test_base32_errors() {
+  assert_eq!(
+    FStr16::from("MZXW6YQA").decode_base32::<4>(),
+    Err(DecodeError::InvalidPadding { offset: 7 })
+  );
+  assert_eq!(
+    FStr16::from("MZXW6YQ=").decode_base32::<5>(),
+    Err(DecodeError::InvalidCharacter { offset: 7 })
+  );
+  assert_eq!(
+    FStr16::from("mzxw6yq=").decode_base32::<4>(),
+    Err(DecodeError::InvalidCharacter { offset: 0 })
+  );
+  assert_eq!(
+    FStr16::from("MZXW6YR=").decode_base32::<4>(),
+    Err(DecodeError::InvalidCharacter { offset: 6 })
+  );
+}
+
+#[cfg(feature = "std")]
+mod std {
+  use std::format;
+
+  use crate::DecodeError;
+
+  #[test]
+  fn test_display() {
+    assert_eq!(
+      format!("{}", DecodeError::InvalidCharacter { offset: 4 }),
+      "Invalid character at offset 4"
+    );
+    assert_eq!(
+      format!(
+        "{}",
+        DecodeError::InvalidLength {
+          length: 3,
+          expected: 4
+        }
+      ),
+      "Encoded length (3) does not match expected length (4)"
+    );
+  }
+}
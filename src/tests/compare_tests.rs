@@ -0,0 +1,136 @@
+use core::cmp::Ordering;
+
+use crate::{Align8, Align16, Align128, BoundedStr, FixedStr};
+
+const WORDS: [&str; 16] = [
+  "",
+  "a",
+  "b",
+  "ab",
+  "abcdefg",
+  "abcdefgh",
+  "abcdefgi",
+  "abcdefghabcdefg",
+  "abcdefghabcdefgh",
+  "abcdefghabcdefgha",
+  "abcdefghabcdefghabcdefgz",
+  "\u{7f}",
+  "\u{80}",
+  "é",
+  "ü",
+  "zzzzzzzzzzzzzzzzzzzzzzzzzzzzzz",
+];
+
+/// Checks that comparisons of strings with capacity `N` order by length
+/// first, then by bytes
+fn check_bounded<const N: usize, A>() {
+  for a in WORDS.iter().filter(|s| s.len() <= N) {
+    for b in WORDS.iter().filter(|s| s.len() <= N) {
+      let x = BoundedStr::<N, A>::try_from(a).unwrap();
+      let y = BoundedStr::<N, A>::try_from(b).unwrap();
+      let expected = a.len().cmp(&b.len()).then(a.cmp(b));
+
+      assert_eq!(x.cmp(&y), expected, "{:?} <=> {:?} (N = {})", a, b, N);
+      assert_eq!(x.partial_cmp(&y), Some(expected));
+      assert_eq!(x == y, a == b);
+    }
+  }
+}
+
+/// Checks that comparisons of NUL-padded strings with capacity `N` match the
+/// byte order
+fn check_fixed<const N: usize, A>() {
+  for a in WORDS.iter().filter(|s| s.len() <= N) {
+    for b in WORDS.iter().filter(|s| s.len() <= N) {
+      let x = FixedStr::<N, A>::try_from(a).unwrap();
+      let y = FixedStr::<N, A>::try_from(b).unwrap();
+
+      assert_eq!(x.cmp(&y), a.cmp(b), "{:?} <=> {:?} (N = {})", a, b, N);
+      assert_eq!(x == y, a == b);
+    }
+  }
+}
+
+#[test]
+fn test_bounded_str() {
+  check_bounded::<1, Align8>();
+  check_bounded::<7, Align8>();
+  check_bounded::<8, Align8>();
+  check_bounded::<15, Align16>();
+  check_bounded::<17, Align8>();
+  check_bounded::<31, Align16>();
+  check_bounded::<127, Align128>();
+}
+
+#[test]
+fn test_fixed_str() {
+  check_fixed::<1, Align8>();
+  check_fixed::<7, Align8>();
+  check_fixed::<16, Align16>();
+  check_fixed::<17, Align8>();
+  check_fixed::<24, Align8>();
+  check_fixed::<33, Align16>();
+  check_fixed::<128, Align128>();
+}
+
+#[test]
+fn test_length_first() {
+  // The length byte precedes the data, so it must take priority
+  let long = BoundedStr::<15, Align16>::try_from("aa").unwrap();
+  let short = BoundedStr::<15, Align16>::try_from("z").unwrap();
+  assert_eq!(short.cmp(&long), Ordering::Less);
+}
+
+#[cfg(feature = "simd")]
+#[test]
+fn test_first_mismatch() {
+  use crate::simd::first_mismatch;
+
+  let a = [7u8; 53];
+
+  assert_eq!(first_mismatch(&a, &a), None);
+  assert_eq!(first_mismatch(&[], &[]), None);
+
+  for i in 0..a.len() {
+    let mut b = a;
+    b[i] = 0;
+    assert_eq!(first_mismatch(&a, &b), Some(i));
+
+    // Only the first mismatch counts
+    if i + 1 < b.len() {
+      b[i + 1] = 0;
+      assert_eq!(first_mismatch(&a, &b), Some(i));
+    }
+  }
+}
+
+#[cfg(feature = "std")]
+mod std_tests {
+  use core::hash::BuildHasher;
+  use std::collections::hash_map::RandomState;
+
+  use super::*;
+  use crate::{BStr63, FStr64, FixedStr, RightSpace};
+
+  #[test]
+  fn test_hash() {
+    let state = RandomState::new();
+
+    for a in WORDS {
+      for b in WORDS {
+        let x = state.hash_one(BStr63::from(a));
+        let y = state.hash_one(BStr63::from(b));
+        assert_eq!(x == y, a == b, "{:?}, {:?}", a, b);
+
+        let x = state.hash_one(FStr64::from(a));
+        let y = state.hash_one(FStr64::from(b));
+        assert_eq!(x == y, a == b, "{:?}, {:?}", a, b);
+      }
+    }
+
+    // Non-NUL padding
+    let hash = |s| state.hash_one(FixedStr::<17, Align8, RightSpace>::from(s));
+    assert_eq!(hash("abc"), hash("abc"));
+    assert_ne!(hash("abc"), hash("abd"));
+  }
+}
@@ -13,3 +13,34 @@ mod std {
     });
   }
 }
+
+#[cfg(feature = "std")]
+mod invalid_bytes {
+  use std::format;
+
+  use crate::{ExceedsCapacity, InvalidBytes};
+
+  #[test]
+  fn test_display() {
+    assert_eq!(
+      format!(
+        "{}",
+        InvalidBytes::ExceedsCapacity(ExceedsCapacity {
+          length: 9,
+          capacity: 7
+        })
+      ),
+      "String length (9) exceeds capacity (7)"
+    );
+
+    assert_eq!(
+      format!("{}", InvalidBytes::InvalidUtf8 { offset: 3 }),
+      "Invalid UTF-8 at offset 3"
+    );
+
+    assert_eq!(
+      format!("{}", InvalidBytes::NonAscii { offset: 2 }),
+      "Non-ASCII byte at offset 2"
+    );
+  }
+}
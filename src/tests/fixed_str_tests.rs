@@ -1,6 +1,9 @@
 use core::mem;
 
-use crate::{ExceedsCapacity, FStr8, FStr16, FStr24, FStr32, FStr64, FStr128};
+use crate::{
+  Align8, Align16, ExceedsCapacity, FStr8, FStr16, FStr24, FStr32, FStr64, FStr128, FixedStr,
+  InvalidBytes, Pad, RightSpace, RightZero, Space,
+};
 
 #[test]
 fn test_size() {
@@ -83,17 +86,159 @@ fn test_exceed_capacity() {
   );
 }
 
+#[test]
+fn test_wire_format_golden() {
+  let golden = include_bytes!("golden/fstr16_v1.bin");
+  let s = FStr16::from("device-01");
+
+  assert_eq!(&s.to_bytes(), golden);
+  assert_eq!(FStr16::try_from_bytes(golden), Ok(s));
+}
+
+#[test]
+fn test_try_from_bytes_invalid_utf8() {
+  assert_eq!(
+    FStr8::try_from_bytes(b"abc\xc3\x28\0\0\0"),
+    Err(InvalidBytes::InvalidUtf8 { offset: 3 })
+  );
+}
+
+const VALID: Result<FStr8, InvalidBytes> = FStr8::const_try_from_bytes(b"abc\0\0\0\0\0");
+
+#[test]
+fn test_const_try_from_bytes() {
+  assert_eq!(VALID, Ok(FStr8::from("abc")));
+  assert_eq!(
+    FStr8::const_try_from_bytes(b"ab\xe2\x82cdef"),
+    Err(InvalidBytes::InvalidUtf8 { offset: 2 })
+  );
+}
+
+#[test]
+fn test_try_from_slice() {
+  assert_eq!(FStr8::try_from_slice(b"abc"), Ok(FStr8::from("abc")));
+  assert_eq!(
+    FixedStr::<8, Align8, RightSpace>::try_from_slice("€".as_bytes()),
+    Ok(FixedStr::from("€"))
+  );
+  assert_eq!(
+    FStr8::try_from_slice(b"abc\xff"),
+    Err(InvalidBytes::InvalidUtf8 { offset: 3 })
+  );
+  assert_eq!(
+    FStr8::try_from_slice(b"123456789"),
+    Err(InvalidBytes::ExceedsCapacity(ExceedsCapacity {
+      length: 9,
+      capacity: 8
+    }))
+  );
+}
+
+#[test]
+fn test_try_from_ascii() {
+  assert_eq!(
+    FStr8::try_from_ascii(b"ACME0001"),
+    Ok(FStr8::from("ACME0001"))
+  );
+  assert_eq!(
+    FStr8::try_from_ascii(b"ACM\xc3\x89001"),
+    Err(InvalidBytes::NonAscii { offset: 3 })
+  );
+}
+
+#[test]
+fn test_try_from_ascii_slice() {
+  assert_eq!(
+    FixedStr::<8, Align8, Space>::try_from_ascii_slice(b"ACME"),
+    Ok(FixedStr::from("ACME"))
+  );
+  assert_eq!(
+    FStr8::try_from_ascii_slice("é".as_bytes()),
+    Err(InvalidBytes::NonAscii { offset: 0 })
+  );
+  assert_eq!(
+    FStr8::try_from_ascii_slice(b"123456789"),
+    Err(InvalidBytes::ExceedsCapacity(ExceedsCapacity {
+      length: 9,
+      capacity: 8
+    }))
+  );
+}
+
+#[test]
+fn test_trimmed_nul() {
+  let s = FStr8::from("ab\0cd");
+  assert_eq!(s.as_str_trimmed(), "ab");
+  assert_eq!(s.trimmed(), "ab\0cd");
+  assert_eq!(s.len(), 5);
+
+  assert!(FStr8::new().is_empty());
+}
+
+#[test]
+fn test_space_padding() {
+  let s = FixedStr::<8, Align8, Space>::from("ACME");
+  assert_eq!(s.as_str(), "ACME    ");
+  assert_eq!(s.trimmed(), "ACME");
+  assert_eq!(s.len(), 4);
+
+  let empty = FixedStr::<8, Align8, Space>::new();
+  assert_eq!(empty.as_str(), "        ");
+  assert!(empty.is_empty());
+}
+
+#[test]
+fn test_right_aligned_padding() {
+  let s = FixedStr::<8, Align8, RightZero>::from("1250");
+  assert_eq!(s.as_str(), "00001250");
+  assert_eq!(s.trimmed(), "1250");
+
+  let s = FixedStr::<8, Align8, RightSpace>::from("€1");
+  assert_eq!(s.as_str(), "    €1");
+  assert_eq!(s.trimmed(), "€1");
+  assert_eq!(s.len(), 4);
+
+  let full = FixedStr::<4, Align8, RightZero>::from("1234");
+  assert_eq!(full.trimmed(), "1234");
+}
+
+#[test]
+fn test_custom_padding() {
+  const S: FixedStr<6, Align8, Pad<b'*', true>> = FixedStr::const_from("ab");
+  assert_eq!(S.as_str(), "****ab");
+  assert_eq!(S.trimmed(), "ab");
+
+  assert_eq!(
+    FixedStr::<2, Align8, Pad<b'*'>>::const_try_from("abc"),
+    None
+  );
+}
+
+#[test]
+fn test_padding_wire_format() {
+  let s = FixedStr::<16, Align16, Space>::from("device-01");
+  assert_eq!(&s.to_bytes(), b"device-01       ");
+  assert_eq!(FixedStr::try_from_bytes(b"device-01       "), Ok(s));
+}
+
 #[cfg(feature = "std")]
 mod std {
   use std::format;
 
-  use crate::FStr32;
+  use crate::{Align8, FStr32, FixedStr, RightSpace};
 
   #[test]
   fn test_debug() {
     let v = FStr32::try_from("abc").unwrap();
     assert_eq!(format!("{v:?}"), "abc");
   }
+
+  #[test]
+  fn test_display_padding() {
+    let v = FixedStr::<8, Align8, RightSpace>::from("42");
+    assert_eq!(format!("{v}"), "      42");
+    assert_eq!(format!("{v:?}"), "42");
+  }
 }
 
 #[cfg(feature = "serde")]
@@ -117,3 +262,511 @@ mod serde_tests {
     );
   }
 }
+
+#[cfg(all(feature = "rkyv", feature = "std"))]
+mod rkyv_tests {
+  use rkyv::rancor::Error;
+  use rkyv::util::AlignedVec;
+
+  use crate::{FStr8, FStr64};
+
+  #[test]
+  fn test_archive_layout() {
+    let s = FStr8::from("abc");
+    let bytes = rkyv::api::high::to_bytes_in::<_, Error>(&s, AlignedVec::<8>::new()).unwrap();
+
+    assert_eq!(&bytes[..], b"abc\0\0\0\0\0");
+  }
+
+  #[test]
+  fn test_access() {
+    let s = FStr64::from("9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08");
+    let bytes = rkyv::api::high::to_bytes_in::<_, Error>(&s, AlignedVec::<64>::new()).unwrap();
+
+    let archived = rkyv::access::<FStr64, Error>(&bytes).unwrap();
+    assert_eq!(archived, &s);
+
+    let deserialised = rkyv::deserialize::<FStr64, Error>(archived).unwrap();
+    assert_eq!(deserialised, s);
+  }
+
+  #[test]
+  fn test_access_invalid_utf8() {
+    let mut bytes = AlignedVec::<8>::new();
+    bytes.extend_from_slice(b"abc\xff\0\0\0\0");
+
+    assert!(rkyv::access::<FStr8, Error>(&bytes).is_err());
+  }
+}
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck_tests {
+  use crate::{FStr8, FStr16};
+
+  #[test]
+  fn test_layout() {
+    let s = FStr8::from("abc");
+    assert_eq!(bytemuck::bytes_of(&s), b"abc\0\0\0\0\0");
+  }
+
+  #[test]
+  fn test_try_cast_slice() {
+    #[repr(C, align(16))]
+    struct Buffer([u8; 32]);
+
+    let buffer = Buffer(*b"0123456789abcdefdevice-01\0\0\0\0\0\0\0");
+
+    let items = bytemuck::checked::try_cast_slice::<u8, FStr16>(&buffer.0).unwrap();
+    assert_eq!(
+      items,
+      [FStr16::from("0123456789abcdef"), FStr16::from("device-01")]
+    );
+  }
+
+  #[test]
+  fn test_try_cast_slice_invalid_utf8() {
+    #[repr(C, align(8))]
+    struct Buffer([u8; 8]);
+
+    let buffer = Buffer(*b"abc\xff\0\0\0\0");
+    assert!(bytemuck::checked::try_cast_slice::<u8, FStr8>(&buffer.0).is_err());
+  }
+}
+
+#[cfg(feature = "zerocopy")]
+mod zerocopy_tests {
+  use zerocopy::IntoBytes;
+
+  use crate::{FStr8, FStr16, FixedStr, InvalidBytes};
+
+  #[test]
+  fn test_layout() {
+    // IntoBytes is derived for unaligned strings, which have no padding
+    let s = FixedStr::<8, ()>::try_from("abc").unwrap();
+    assert_eq!(IntoBytes::as_bytes(&s), b"abc\0\0\0\0\0");
+
+    let s = FStr8::from("abc");
+    assert_eq!(s.as_bytes(), b"abc\0\0\0\0\0");
+  }
+
+  #[test]
+  fn test_try_slice_from_bytes() {
+    #[repr(C, align(16))]
+    struct Buffer([u8; 32]);
+
+    let buffer = Buffer(*b"0123456789abcdefdevice-01\0\0\0\0\0\0\0");
+
+    let items = FStr16::try_slice_from_bytes(&buffer.0).unwrap();
+    assert_eq!(
+      items,
+      [FStr16::from("0123456789abcdef"), FStr16::from("device-01")]
+    );
+
+    assert_eq!(
+      FStr16::try_slice_from_bytes(&buffer.0[..24]),
+      Err(InvalidBytes::Layout)
+    );
+  }
+
+  #[test]
+  fn test_try_ref_from_bytes_invalid_utf8() {
+    #[repr(C, align(8))]
+    struct Buffer([u8; 8]);
+
+    let buffer = Buffer(*b"abc\xff\0\0\0\0");
+    assert_eq!(
+      FStr8::try_ref_from_bytes(&buffer.0),
+      Err(InvalidBytes::InvalidUtf8 { offset: 3 })
+    );
+  }
+}
+
+#[cfg(all(feature = "borsh", feature = "std"))]
+mod borsh_tests {
+  use std::string::String;
+
+  use crate::{Align8, FStr8, FixedStr, RightZero};
+
+  #[test]
+  fn test_round_trip() {
+    let s = FStr8::from("abcdefgh");
+    let bytes = borsh::to_vec(&s).unwrap();
+
+    assert_eq!(bytes, borsh::to_vec(&String::from("abcdefgh")).unwrap());
+    assert_eq!(borsh::from_slice::<FStr8>(&bytes).unwrap(), s);
+  }
+
+  #[test]
+  fn test_pads_shorter_string() {
+    let bytes = borsh::to_vec(&String::from("abc")).unwrap();
+    assert_eq!(
+      borsh::from_slice::<FStr8>(&bytes).unwrap(),
+      FStr8::from("abc")
+    );
+  }
+
+  #[test]
+  fn test_exceeds_capacity() {
+    let bytes = borsh::to_vec(&String::from("abcdefghi")).unwrap();
+    assert!(borsh::from_slice::<FStr8>(&bytes).is_err());
+  }
+
+  #[test]
+  fn test_padding() {
+    type Amount = FixedStr<8, Align8, RightZero>;
+
+    let bytes = borsh::to_vec(&String::from("42")).unwrap();
+    let s = borsh::from_slice::<Amount>(&bytes).unwrap();
+    assert_eq!(s.as_str(), "00000042");
+
+    let bytes = borsh::to_vec(&s).unwrap();
+    assert_eq!(borsh::from_slice::<Amount>(&bytes).unwrap(), s);
+  }
+}
+
+#[cfg(all(feature = "bincode", feature = "std"))]
+mod bincode_tests {
+  use std::string::String;
+
+  use crate::FStr8;
+
+  #[test]
+  fn test_round_trip() {
+    let config = bincode::config::standard();
+    let s = FStr8::from("abc");
+    let bytes = bincode::encode_to_vec(s, config).unwrap();
+
+    assert_eq!(
+      bincode::decode_from_slice::<FStr8, _>(&bytes, config).unwrap(),
+      (s, bytes.len())
+    );
+  }
+
+  #[test]
+  fn test_exceeds_capacity() {
+    let config = bincode::config::standard();
+    let bytes = bincode::encode_to_vec(String::from("abcdefghi"), config).unwrap();
+
+    assert!(bincode::decode_from_slice::<FStr8, _>(&bytes, config).is_err());
+  }
+}
+
+#[cfg(feature = "postcard")]
+mod postcard_tests {
+  use postcard::experimental::max_size::MaxSize;
+
+  use crate::{FStr8, FStr128};
+
+  #[test]
+  fn test_max_size() {
+    assert_eq!(FStr8::POSTCARD_MAX_SIZE, 1 + 8);
+    assert_eq!(FStr128::POSTCARD_MAX_SIZE, 2 + 128);
+  }
+
+  #[cfg(feature = "serde")]
+  #[test]
+  fn test_max_size_worst_case() {
+    let mut buf = [0u8; FStr128::POSTCARD_MAX_SIZE];
+
+    // Any FixedStr is encoded with its full capacity
+    let encoded = postcard::to_slice(&FStr128::from("abc"), &mut buf).unwrap();
+    assert_eq!(encoded.len(), FStr128::POSTCARD_MAX_SIZE);
+  }
+}
+
+#[cfg(feature = "schemars")]
+mod schemars_tests {
+  use schemars::{JsonSchema, SchemaGenerator, json_schema};
+
+  use crate::{Align16, FStr16, FixedStr, Pad, Space};
+
+  #[test]
+  fn test_schema() {
+    let schema = FStr16::json_schema(&mut SchemaGenerator::default());
+    assert_eq!(
+      schema,
+      json_schema!({
+        "type": "string",
+        "maxLength": 16,
+        "pattern": "^[^\\u0000]*\\u0000*$",
+      })
+    );
+  }
+
+  #[test]
+  fn test_schema_padding() {
+    let schema =
+      FixedStr::<16, Align16, Pad<0, true>>::json_schema(&mut SchemaGenerator::default());
+    assert_eq!(schema.get("pattern").unwrap(), "^\\u0000*[^\\u0000]*$");
+
+    // Spaces may be part of the content, so there is no pattern
+    let schema = FixedStr::<16, Align16, Space>::json_schema(&mut SchemaGenerator::default());
+    assert_eq!(
+      schema,
+      json_schema!({
+        "type": "string",
+        "maxLength": 16,
+      })
+    );
+  }
+}
+
+#[cfg(all(feature = "proptest", feature = "std"))]
+mod proptest_tests {
+  use std::vec::Vec;
+
+  use proptest::prelude::*;
+  use proptest::strategy::ValueTree;
+  use proptest::test_runner::TestRunner;
+
+  use crate::{FStr8, FStr16};
+
+  proptest! {
+    #[test]
+    fn test_wire_format_roundtrip(s: FStr16) {
+      let bytes: [u8; 16] = s.to_bytes();
+      prop_assert_eq!(FStr16::try_from_bytes(&bytes), Ok(s));
+    }
+
+    #[test]
+    fn test_str_roundtrip(s: FStr8) {
+      prop_assert_eq!(FStr8::try_from(s.as_str()), Ok(s));
+    }
+  }
+
+  #[test]
+  fn test_edge_cases() {
+    let mut runner = TestRunner::deterministic();
+    let strategy = any::<FStr16>();
+
+    let values: Vec<FStr16> = (0..1000)
+      .map(|_| strategy.new_tree(&mut runner).unwrap().current())
+      .collect();
+
+    assert!(values.iter().any(|s| s.as_bytes() == &[0; 16]));
+    assert!(values.iter().any(|s| s.as_bytes()[15] != 0));
+    assert!(values.iter().any(|s| s.as_bytes()[15] >= 0x80));
+  }
+}
+
+#[cfg(all(feature = "quickcheck", feature = "std"))]
+mod quickcheck_tests {
+  use quickcheck::quickcheck;
+
+  use crate::FStr16;
+
+  #[test]
+  fn test_str_roundtrip() {
+    fn prop(s: FStr16) -> bool {
+      FStr16::try_from(s.as_str()) == Ok(s)
+    }
+
+    quickcheck(prop as fn(FStr16) -> bool);
+  }
+}
+
+#[cfg(all(feature = "arbitrary", feature = "std"))]
+mod arbitrary_tests {
+  use std::vec::Vec;
+
+  use arbitrary::{Arbitrary, Unstructured};
+
+  use crate::FStr16;
+
+  #[test]
+  fn test_wire_format_roundtrip() {
+    let data: Vec<u8> = (0..4096u32)
+      .map(|i| (i.wrapping_mul(2654435761) >> 13) as u8)
+      .collect();
+    let mut u = Unstructured::new(&data);
+
+    while !u.is_empty() {
+      let s = FStr16::arbitrary(&mut u).unwrap();
+
+      let bytes: [u8; 16] = s.to_bytes();
+      assert_eq!(FStr16::try_from_bytes(&bytes), Ok(s));
+    }
+  }
+}
+
+#[cfg(feature = "ufmt")]
+mod ufmt_tests {
+  use ufmt::uwrite;
+
+  use crate::{Align16, BStr31, FStr16, FixedStr, Space};
+
+  #[test]
+  fn test_display_trimmed() {
+    let mut out = BStr31::new();
+
+    uwrite!(out, "<{}>", FStr16::from("abc")).unwrap();
+    assert_eq!(out.as_str(), "<abc>");
+  }
+
+  #[test]
+  fn test_debug_trimmed() {
+    let mut out = BStr31::new();
+
+    uwrite!(out, "<{:?}>", FStr16::from("abc")).unwrap();
+    assert_eq!(out.as_str(), "<abc>");
+  }
+
+  #[test]
+  fn test_display_padding() {
+    let mut out = BStr31::new();
+
+    uwrite!(out, "<{}>", FixedStr::<16, Align16, Space>::from("a b")).unwrap();
+    assert_eq!(out.as_str(), "<a b>");
+  }
+}
+
+#[cfg(feature = "defmt")]
+mod defmt_tests {
+  use crate::FStr16;
+
+  fn assert_format<T: defmt::Format>() {}
+
+  #[test]
+  fn test_format() {
+    assert_format::<FStr16>();
+  }
+}
+
+#[cfg(feature = "heapless")]
+mod heapless_tests {
+  use crate::{Align8, Align16, ExceedsCapacity, FStr8, FStr16, FixedStr, RightSpace, Space};
+
+  #[test]
+  fn test_from_heapless() {
+    let s: heapless::String<8> = heapless::String::try_from("abc").unwrap();
+    assert_eq!(FStr16::from(s), FStr16::from("abc"));
+  }
+
+  #[test]
+  fn test_into_heapless_trimmed() {
+    let s: heapless::String<8> = FStr8::from("abc").into();
+    assert_eq!(s.as_str(), "abc");
+
+    let s: heapless::String<8> = FixedStr::<8, Align8, Space>::from("a\0c").into();
+    assert_eq!(s.as_str(), "a\0c");
+
+    let s = FixedStr::<8, Align8, RightSpace>::from(heapless::String::<8>::try_from("42").unwrap());
+    assert_eq!(s.as_str(), "      42");
+  }
+
+  #[test]
+  fn test_try_heapless() {
+    let s: heapless::String<16> = heapless::String::try_from("abcdefghi").unwrap();
+    assert!(FStr8::try_from_heapless(&s).is_err());
+
+    let s = FixedStr::<16, Align16, Space>::try_from_heapless(&s).unwrap();
+    assert_eq!(s.try_into_heapless::<9>().unwrap().as_str(), "abcdefghi");
+    assert_eq!(
+      s.try_into_heapless::<8>(),
+      Err(ExceedsCapacity {
+        length: 9,
+        capacity: 8
+      })
+    );
+  }
+}
+
+#[cfg(feature = "arrayvec")]
+mod arrayvec_tests {
+  use crate::{FStr8, FStr16};
+
+  #[test]
+  fn test_from_arrayvec() {
+    let s = arrayvec::ArrayString::<8>::from("abcdefgh").unwrap();
+    assert_eq!(FStr8::from(s), FStr8::from("abcdefgh"));
+  }
+
+  #[test]
+  fn test_into_arrayvec_trimmed() {
+    let s: arrayvec::ArrayString<16> = FStr16::from("abc").into();
+    assert_eq!(s.as_str(), "abc");
+  }
+
+  #[test]
+  fn test_try_arrayvec() {
+    let s = arrayvec::ArrayString::<16>::from("abcdefghi").unwrap();
+    assert!(FStr8::try_from_arrayvec(&s).is_err());
+
+    let s = FStr16::try_from_arrayvec(&s).unwrap();
+    assert_eq!(s.try_into_arrayvec::<9>().unwrap().as_str(), "abcdefghi");
+    assert!(s.try_into_arrayvec::<8>().is_err());
+  }
+}
+
+#[cfg(feature = "smol_str")]
+mod smol_str_tests {
+  use smol_str::SmolStr;
+
+  use crate::{Align8, FStr16, FixedStr, RightSpace};
+
+  #[test]
+  fn test_into_smol_str_trimmed() {
+    let s = SmolStr::from(FStr16::from("abc"));
+
+    assert_eq!(s, "abc");
+    assert!(!s.is_heap_allocated());
+  }
+
+  #[test]
+  fn test_try_from_smol_str() {
+    assert_eq!(
+      FStr16::try_from(&SmolStr::new("abc")),
+      Ok(FStr16::from("abc"))
+    );
+  }
+
+  #[test]
+  fn test_eq_trimmed() {
+    assert_eq!(FStr16::from("abc"), SmolStr::new("abc"));
+    assert_eq!(SmolStr::new("abc"), FStr16::from("abc"));
+
+    let s = FixedStr::<8, Align8, RightSpace>::from("a b");
+    assert_eq!(s, SmolStr::new("a b"));
+    assert_eq!(SmolStr::from(s), "a b");
+  }
+}
+
+#[cfg(feature = "compact_str")]
+mod compact_str_tests {
+  use compact_str::CompactString;
+
+  use crate::FStr16;
+
+  #[test]
+  fn test_into_compact_str_trimmed() {
+    assert_eq!(CompactString::from(FStr16::from("abc")), "abc");
+  }
+
+  #[test]
+  fn test_eq_trimmed() {
+    assert_eq!(FStr16::from("abc"), CompactString::new("abc"));
+    assert_eq!(CompactString::new("abc"), FStr16::from("abc"));
+  }
+}
+
+#[cfg(feature = "bstr")]
+mod bstr_tests {
+  use bstr::{BStr, ByteSlice};
+
+  use crate::FStr8;
+
+  #[test]
+  fn test_as_bstr() {
+    let s = FStr8::from("abc");
+
+    assert_eq!(s.as_bstr(), b"abc\0\0\0\0\0".as_bstr());
+    assert_eq!(s.as_bstr().trim_end_with(|c| c == '\0'), b"abc");
+    assert_eq!(s.as_bytes().find_byte(0), Some(3));
+  }
+
+  #[test]
+  fn test_eq_trimmed() {
+    assert_eq!(FStr8::from("abc"), *BStr::new("abc"));
+    assert_eq!(*BStr::new("abc"), FStr8::from("abc"));
+  }
+}
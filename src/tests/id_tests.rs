@@ -0,0 +1,168 @@
+use crate::{DecodeError, HexDigest, UlidStr, UuidStr};
+
+const UUID: &str = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+const UUID_BYTES: [u8; 16] = [
+  0x67, 0xe5, 0x50, 0x44, 0x10, 0xb1, 0x42, 0x6f, 0x92, 0x47, 0xbb, 0x68, 0x0e, 0x5f, 0xe0, 0xc8,
+];
+
+#[test]
+fn test_uuid_parse() {
+  let id = UuidStr::parse(UUID).unwrap();
+  assert_eq!(id.as_str(), UUID);
+  assert_eq!(id.to_bytes(), UUID_BYTES);
+
+  let upper = UuidStr::parse("67E55044-10B1-426F-9247-BB680E5FE0C8").unwrap();
+  assert_eq!(upper, id);
+}
+
+#[test]
+fn test_uuid_from_bytes() {
+  const ID: UuidStr = UuidStr::from_bytes(&UUID_BYTES);
+  assert_eq!(ID.as_str(), UUID);
+}
+
+#[test]
+fn test_uuid_errors() {
+  assert_eq!(
+    UuidStr::parse("67e5504410b1426f9247bb680e5fe0c8"),
+    Err(DecodeError::InvalidLength {
+      length: 32,
+      expected: 36
+    })
+  );
+  assert_eq!(
+    UuidStr::parse("67e55044-10b1-426f_9247-bb680e5fe0c8"),
+    Err(DecodeError::InvalidCharacter { offset: 18 })
+  );
+  assert_eq!(
+    UuidStr::parse("67e55044-10b1-426f-9247-bb680e5fe0cg"),
+    Err(DecodeError::InvalidCharacter { offset: 35 })
+  );
+}
+
+#[test]
+fn test_uuid_ord() {
+  let mut low = UUID_BYTES;
+  low[0] = 0x0a;
+  let mut high = UUID_BYTES;
+  high[0] = 0xa0;
+
+  assert!(UuidStr::from_bytes(&low) < UuidStr::from_bytes(&UUID_BYTES));
+  assert!(UuidStr::from_bytes(&UUID_BYTES) < UuidStr::from_bytes(&high));
+}
+
+#[test]
+fn test_ulid_roundtrip() {
+  let id = UlidStr::parse("01ARZ3NDEKTSV4RRFFQ69G5FAV").unwrap();
+  assert_eq!(UlidStr::from_bytes(&id.to_bytes()), id);
+  assert_eq!(id.timestamp_ms(), 1_469_922_850_259);
+
+  let id = UlidStr::parse("01ARYZ6S41TSV4RRFFQ69G5FAV").unwrap();
+  assert_eq!(id.timestamp_ms(), 1_469_918_176_385);
+
+  assert_eq!(
+    UlidStr::from_bytes(&[0; 16]).as_str(),
+    "00000000000000000000000000"
+  );
+  assert_eq!(
+    UlidStr::from_bytes(&[0xff; 16]).as_str(),
+    "7ZZZZZZZZZZZZZZZZZZZZZZZZZ"
+  );
+}
+
+#[test]
+fn test_ulid_normalisation() {
+  let id = UlidStr::parse("01arz3ndektsv4rrffq69g5fav").unwrap();
+  assert_eq!(id.as_str(), "01ARZ3NDEKTSV4RRFFQ69G5FAV");
+
+  let aliased = UlidStr::parse("OLARZ3NDEKTSV4RRFFQ69G5FAV").unwrap();
+  assert_eq!(
+    aliased,
+    UlidStr::parse("01ARZ3NDEKTSV4RRFFQ69G5FAV").unwrap()
+  );
+}
+
+#[test]
+fn test_ulid_errors() {
+  assert_eq!(
+    UlidStr::parse("01ARZ3NDEKTSV4RRFFQ69G5FA"),
+    Err(DecodeError::InvalidLength {
+      length: 25,
+      expected: 26
+    })
+  );
+  assert_eq!(
+    UlidStr::parse("01ARZ3NDEKTSV4RRFFQ69G5FAU"),
+    Err(DecodeError::InvalidCharacter { offset: 25 })
+  );
+  assert_eq!(
+    UlidStr::parse("81ARZ3NDEKTSV4RRFFQ69G5FAV"),
+    Err(DecodeError::InvalidCharacter { offset: 0 })
+  );
+}
+
+#[test]
+fn test_ulid_ord() {
+  let mut earlier = [0u8; 16];
+  earlier[5] = 1;
+  let mut later = [0u8; 16];
+  later[4] = 1;
+
+  assert!(UlidStr::from_bytes(&earlier) < UlidStr::from_bytes(&later));
+  assert!(
+    UlidStr::from_bytes(&earlier).timestamp_ms() < UlidStr::from_bytes(&later).timestamp_ms()
+  );
+}
+
+#[test]
+fn test_hex_digest() {
+  let digest = HexDigest::<64>::from_bytes(&[0x5a; 32]);
+  assert_eq!(digest.as_str(), "5a".repeat(32).as_str());
+  assert_eq!(digest.to_bytes(), [0x5a; 32]);
+
+  assert_eq!(HexDigest::<4>::parse("AbCd").unwrap().as_str(), "abcd");
+  assert!(HexDigest::<4>::from_bytes(&[0x0f, 0xff]) < HexDigest::<4>::from_bytes(&[0xf0, 0x00]));
+}
+
+#[test]
+fn test_hex_digest_errors() {
+  assert_eq!(
+    HexDigest::<4>::parse("abc"),
+    Err(DecodeError::InvalidLength {
+      length: 3,
+      expected: 4
+    })
+  );
+  assert_eq!(
+    HexDigest::<4>::parse("abcx"),
+    Err(DecodeError::InvalidCharacter { offset: 3 })
+  );
+}
+
+#[cfg(feature = "serde")]
+mod serde_tests {
+  use crate::UuidStr;
+
+  #[test]
+  fn test_roundtrip() {
+    let id = UuidStr::parse(super::UUID).unwrap();
+    let json = serde_json::to_string(&id).unwrap();
+    assert_eq!(serde_json::from_str::<UuidStr>(&json).unwrap(), id);
+
+    assert!(serde_json::from_str::<UuidStr>("\"abc\"").is_err());
+  }
+}
+
+#[cfg(feature = "uuid")]
+mod uuid_tests {
+  use crate::UuidStr;
+
+  #[test]
+  fn test_uuid_conversion() {
+    let uuid = uuid::Uuid::from_bytes(super::UUID_BYTES);
+    let id = UuidStr::from(uuid);
+
+    assert_eq!(id.as_str(), super::UUID);
+    assert_eq!(uuid::Uuid::from(id), uuid);
+  }
+}
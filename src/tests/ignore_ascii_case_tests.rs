@@ -0,0 +1,148 @@
+use core::cmp::Ordering;
+
+use crate::{
+  BStr15, BStr63, CaseInsensitive, FStr16, FixedStr, IgnoreAsciiCase, RightSpace, StrVec28,
+};
+
+#[test]
+fn test_bounded_str() {
+  let s = BStr63::from("Content-Type: Text/HTML");
+  assert!(s.eq_ignore_ascii_case("content-type: text/html"));
+  assert!(!s.eq_ignore_ascii_case("content-type: text/htm"));
+  assert!(!s.eq_ignore_ascii_case("content-type: text/htmx"));
+
+  assert_eq!(
+    s.cmp_ignore_ascii_case("CONTENT-TYPE: TEXT/HTML"),
+    Ordering::Equal
+  );
+  assert_eq!(
+    s.cmp_ignore_ascii_case("content-type: text/i"),
+    Ordering::Less
+  );
+  assert_eq!(s.cmp_ignore_ascii_case("content-type"), Ordering::Greater);
+  assert_eq!(BStr15::from("").cmp_ignore_ascii_case("a"), Ordering::Less);
+}
+
+#[test]
+fn test_fixed_str() {
+  let s = FStr16::from("Host");
+  assert!(s.eq_ignore_ascii_case("HOST"));
+  assert_eq!(s.cmp_ignore_ascii_case("hostname"), Ordering::Less);
+
+  let s = FixedStr::<8, crate::Align8, RightSpace>::from("Ab");
+  assert!(s.eq_ignore_ascii_case("aB"));
+}
+
+#[test]
+fn test_str_vec() {
+  let a = StrVec28::try_from(["Accept", "", "X-Forwarded-For"]).unwrap();
+  let b = StrVec28::try_from(["ACCEPT", "", "x-forwarded-for"]).unwrap();
+  let c = StrVec28::try_from(["Accept", "x-forwarded-for"]).unwrap();
+
+  assert!(a.case_insensitive_eq(&b));
+  assert!(!a.case_insensitive_eq(&c));
+  assert_eq!(a.case_insensitive_cmp(&b), Ordering::Equal);
+  assert_eq!(a.case_insensitive_cmp(&c), Ordering::Less);
+  assert_eq!(c.case_insensitive_cmp(&StrVec28::new()), Ordering::Greater);
+}
+
+#[test]
+fn test_generic_bound() {
+  fn contains<T: CaseInsensitive>(items: &[T], needle: &T) -> bool {
+    items.iter().any(|item| item.case_insensitive_eq(needle))
+  }
+
+  let items = [BStr15::from("GET"), BStr15::from("POST")];
+  assert!(contains(&items, &BStr15::from("post")));
+  assert!(!contains(&items, &BStr15::from("PUT")));
+}
+
+#[test]
+fn test_cmp_matches_lowercase() {
+  let words = [
+    "",
+    "a",
+    "A",
+    "ab",
+    "aB",
+    "abcdefgh",
+    "ABCDEFGHI",
+    "abcdefgZ",
+    "Z",
+    "[",
+    "_",
+    "zz",
+    "é",
+  ];
+
+  for a in words {
+    for b in words {
+      let lower = |s: &'static str| s.bytes().map(|b| b.to_ascii_lowercase());
+      let expected = lower(a).cmp(lower(b));
+
+      assert_eq!(
+        IgnoreAsciiCase(BStr15::from(a)).cmp(&IgnoreAsciiCase(BStr15::from(b))),
+        expected,
+        "{} <=> {}",
+        a,
+        b
+      );
+      assert_eq!(
+        IgnoreAsciiCase(a) == IgnoreAsciiCase(b),
+        expected == Ordering::Equal
+      );
+    }
+  }
+}
+
+#[cfg(feature = "std")]
+mod std_tests {
+  use core::hash::BuildHasher;
+  use std::collections::hash_map::RandomState;
+  use std::collections::{BTreeSet, HashMap};
+  use std::string::String;
+  use std::vec::Vec;
+
+  use super::*;
+
+  #[test]
+  fn test_hash() {
+    let state = RandomState::new();
+    let hash = |s: &str| state.hash_one(IgnoreAsciiCase(s));
+
+    assert_eq!(hash("Content-Length"), hash("content-length"));
+    assert_eq!(hash("x"), hash("X"));
+    assert_eq!(
+      state.hash_one(IgnoreAsciiCase(BStr63::from("Host"))),
+      state.hash_one(IgnoreAsciiCase(String::from("hOST")))
+    );
+
+    let a = StrVec28::try_from(["Accept", "Host"]).unwrap();
+    let b = StrVec28::try_from(["accept", "HOST"]).unwrap();
+    assert_eq!(
+      state.hash_one(IgnoreAsciiCase(a)),
+      state.hash_one(IgnoreAsciiCase(b))
+    );
+  }
+
+  #[test]
+  fn test_collections() {
+    let mut headers = HashMap::new();
+    headers.insert(IgnoreAsciiCase(BStr63::from("Content-Type")), 1);
+    headers.insert(IgnoreAsciiCase(BStr63::from("content-type")), 2);
+
+    assert_eq!(headers.len(), 1);
+    let (key, value) = headers.iter().next().unwrap();
+    assert_eq!(key.0.as_str(), "Content-Type");
+    assert_eq!(*value, 2);
+
+    let mut hosts = BTreeSet::new();
+
+    for host in ["b.example", "A.example", "a.EXAMPLE", "C.example"] {
+      hosts.insert(IgnoreAsciiCase(FStr16::from(host)));
+    }
+
+    let hosts: Vec<_> = hosts.iter().map(|s| s.0.trimmed()).collect();
+    assert_eq!(hosts, ["A.example", "b.example", "C.example"]);
+  }
+}
@@ -0,0 +1,133 @@
+use crate::{
+  Align8, BStr7, BStr15, BStr31, ExceedsCapacity, InvalidStr, PackedStr10, PackedStr21,
+  ValidationError,
+};
+
+const ALL: &str = "-.0123456789_abcdefghijklmnopqrstuvwxyz";
+
+#[test]
+fn test_capacity() {
+  assert_eq!(PackedStr10::CAPACITY, 10);
+  assert_eq!(PackedStr21::CAPACITY, 21);
+  assert_eq!(size_of::<PackedStr10>(), 8);
+  assert_eq!(size_of::<PackedStr21>(), 16);
+}
+
+#[test]
+fn test_roundtrip() {
+  for chunk in ALL.as_bytes().chunks(10) {
+    let s = core::str::from_utf8(chunk).unwrap();
+    let packed = PackedStr10::try_from(s).unwrap();
+    assert_eq!(BStr15::from(packed).as_str(), s);
+    assert_eq!(packed.len(), s.len());
+  }
+
+  let s = &ALL[..21];
+  let packed = PackedStr21::try_from(s).unwrap();
+  assert_eq!(BStr31::from(packed).as_str(), s);
+  assert_eq!(packed.char_at(20), s.chars().nth(20));
+  assert_eq!(packed.char_at(21), None);
+
+  assert!(PackedStr10::new().is_empty());
+  assert_eq!(BStr15::from(PackedStr10::new()).as_str(), "");
+}
+
+#[test]
+fn test_try_into_bounded() {
+  let packed = PackedStr21::try_from("abc").unwrap();
+  let s: BStr7 = packed.try_into_bounded().unwrap();
+  assert_eq!(s.as_str(), "abc");
+
+  let packed = PackedStr10::try_from("abcdefgh").unwrap();
+  assert_eq!(
+    packed.try_into_bounded::<7, Align8>(),
+    Err(ExceedsCapacity {
+      length: 8,
+      capacity: 7,
+    })
+  );
+}
+
+#[test]
+fn test_errors() {
+  assert_eq!(
+    PackedStr10::try_from("abcdefghijk"),
+    Err(ValidationError::ExceedsCapacity(ExceedsCapacity {
+      length: 11,
+      capacity: 10,
+    }))
+  );
+  assert_eq!(
+    PackedStr10::try_from("abC"),
+    Err(ValidationError::Invalid(InvalidStr { offset: 2 }))
+  );
+  assert_eq!(
+    PackedStr10::try_from("a\0"),
+    Err(ValidationError::Invalid(InvalidStr { offset: 1 }))
+  );
+  assert!(PackedStr21::try_from("ä").is_err());
+}
+
+#[test]
+fn test_order() {
+  let words = [
+    "",
+    "-",
+    ".",
+    "0",
+    "0a",
+    "9",
+    "_",
+    "a",
+    "a-",
+    "a0",
+    "aa",
+    "ab",
+    "b",
+    "z",
+    "zzzzzzzzzz",
+  ];
+
+  for pair in words.windows(2) {
+    let a = PackedStr10::try_from(pair[0]).unwrap();
+    let b = PackedStr10::try_from(pair[1]).unwrap();
+    assert!(pair[0] < pair[1]);
+    assert!(a < b, "{} < {}", pair[0], pair[1]);
+  }
+}
+
+#[test]
+fn test_bits() {
+  let s = PackedStr10::const_from("aapl");
+  assert_eq!(PackedStr10::try_from_bits(s.to_bits()), Some(s));
+  assert_eq!(PackedStr10::try_from_bits(0), Some(PackedStr10::new()));
+  assert_eq!(BStr15::from("aapl").try_into(), Ok(s));
+
+  // Unused low bits
+  assert_eq!(PackedStr10::try_from_bits(s.to_bits() | 1), None);
+  // Code beyond the alphabet
+  assert_eq!(PackedStr10::try_from_bits(63 << 58), None);
+  // Character after the end
+  assert_eq!(PackedStr10::try_from_bits(14 << 52), None);
+}
+
+#[test]
+fn test_const() {
+  const S: PackedStr21 = PackedStr21::const_from("brk.b");
+  const LEN: usize = S.len();
+  assert_eq!(LEN, 5);
+}
+
+#[cfg(feature = "serde")]
+mod serde_tests {
+  use super::*;
+
+  #[test]
+  fn test_serde() {
+    let s = PackedStr10::try_from("goog").unwrap();
+    let json = serde_json::to_string(&s).unwrap();
+    assert_eq!(json, "\"goog\"");
+    assert_eq!(serde_json::from_str::<PackedStr10>(&json).unwrap(), s);
+    assert!(serde_json::from_str::<PackedStr10>("\"GOOG\"").is_err());
+  }
+}
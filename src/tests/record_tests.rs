@@ -0,0 +1,103 @@
+use crate::{Align8, BStr7, FStr8, FStr16, FixedRecord, FixedStr, InvalidBytes, RightZero, Space};
+
+#[derive(FixedRecord, Debug, PartialEq)]
+struct Customer {
+  #[record(pad = b' ')]
+  id: FStr8,
+  name: FixedStr<16, Align8, Space>,
+  #[record(pad = '0', align = "right")]
+  balance: BStr7,
+}
+
+#[derive(FixedRecord, Debug, PartialEq)]
+struct Pair(FStr8, FixedStr<4, Align8, RightZero>);
+
+#[test]
+fn test_size() {
+  assert_eq!(Customer::SIZE, 8 + 16 + 7);
+  assert_eq!(Pair::SIZE, 12);
+}
+
+#[test]
+fn test_parse() {
+  let customer = Customer::parse(b"C-0042  Jane Doe        0001999").unwrap();
+
+  assert_eq!(customer.id, FStr8::from("C-0042"));
+  assert_eq!(customer.name.trimmed(), "Jane Doe");
+  assert_eq!(customer.balance.as_str(), "1999");
+}
+
+#[test]
+fn test_roundtrip() {
+  let customer = Customer {
+    id: FStr8::from("C-7"),
+    name: "Zoë".into(),
+    balance: BStr7::from("15"),
+  };
+
+  let bytes = customer.write();
+  assert_eq!(&bytes, "C-7     Zoë            0000015".as_bytes());
+  assert_eq!(Customer::parse(&bytes), Ok(customer));
+}
+
+#[test]
+fn test_roundtrip_zero() {
+  let customer = Customer {
+    id: FStr8::from("C-8"),
+    name: "Max".into(),
+    balance: BStr7::from("0"),
+  };
+
+  let bytes = customer.write();
+  assert_eq!(&bytes[24..], b"0000000");
+  assert_eq!(Customer::parse(&bytes), Ok(customer));
+
+  let pair = Pair(FStr8::from("ab"), FixedStr::from("0"));
+  assert_eq!(Pair::parse(&pair.write()), Ok(pair));
+}
+
+#[test]
+fn test_tuple_struct() {
+  let pair = Pair(FStr8::from("ab"), FixedStr::from("7"));
+
+  let bytes = pair.write();
+  assert_eq!(&bytes[..8], b"ab\0\0\0\0\0\0");
+  assert_eq!(&bytes[8..], b"0007");
+  assert_eq!(Pair::parse(&bytes), Ok(pair));
+}
+
+#[test]
+fn test_default_padding_is_nul() {
+  #[derive(FixedRecord)]
+  struct Record {
+    a: BStr7,
+    b: FStr16,
+  }
+
+  let record = Record {
+    a: BStr7::from("abc"),
+    b: FStr16::from("def"),
+  };
+
+  let mut expected = [0u8; 23];
+  expected[..3].copy_from_slice(b"abc");
+  expected[7..10].copy_from_slice(b"def");
+  assert_eq!(record.write(), expected);
+}
+
+#[test]
+fn test_invalid_utf8_offset() {
+  let mut bytes = *b"C-0042  Jane Doe        0001999";
+  bytes[10] = 0xff;
+
+  assert_eq!(
+    Customer::parse(&bytes),
+    Err(InvalidBytes::InvalidUtf8 { offset: 10 })
+  );
+}
+
+#[test]
+fn test_non_padding_bytes_are_kept() {
+  let customer = Customer::parse(b"C-0042  Jane Doe         19 99 ").unwrap();
+  assert_eq!(customer.balance.as_str(), " 19 99 ");
+}
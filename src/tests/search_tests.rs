@@ -0,0 +1,166 @@
+use crate::search::{MatchIndices, Matcher, Scalar, Swar, find, rfind};
+use crate::{BStr15, BStr127, ExceedsCapacity, StrVec, StrVec28};
+
+const HAYSTACKS: [&str; 10] = [
+  "",
+  "a",
+  ",",
+  "a,b",
+  ",,,",
+  "2024-01-01T00:00:00Z INFO  server: listening on 0.0.0.0:8080",
+  "key=value;key=value;;key=",
+  "abababababababababababababababababab",
+  "äöü,ßé,🦀🦀,ä",
+  "\0\0,\0xx\u{7f}\u{80}",
+];
+
+const NEEDLES: [&str; 12] = [
+  "", "a", ",", "ab", "aba", ";;", "key=", ":", "🦀", "ä", "\0", "missing",
+];
+
+/// Checks the search functions of matcher `M` against [str]
+fn check<M: Matcher>() {
+  for haystack in HAYSTACKS {
+    for needle in NEEDLES {
+      let h = haystack.as_bytes();
+      let n = needle.as_bytes();
+
+      assert_eq!(
+        find::<M>(h, n),
+        haystack.find(needle),
+        "find({:?}, {:?})",
+        haystack,
+        needle
+      );
+      assert_eq!(
+        rfind::<M>(h, n),
+        haystack.rfind(needle),
+        "rfind({:?}, {:?})",
+        haystack,
+        needle
+      );
+      assert!(
+        MatchIndices::<M>::new(haystack, needle).eq(haystack.match_indices(needle).map(|(i, _)| i)),
+        "match_indices({:?}, {:?})",
+        haystack,
+        needle
+      );
+
+      match StrVec::<u128, 128, ()>::split_with::<M>(haystack, needle) {
+        // A StrVec item consisting of a NUL byte is read as an empty item
+        Ok(vec) => assert!(
+          vec.iter().eq(
+            haystack
+              .split(needle)
+              .map(|s| if s == "\0" { "" } else { s })
+          ),
+          "split({:?}, {:?})",
+          haystack,
+          needle
+        ),
+        // Only splitting into single characters exceeds the capacity
+        Err(_) => assert!(needle.is_empty()),
+      }
+    }
+  }
+}
+
+#[test]
+fn test_scalar() {
+  check::<Scalar>();
+}
+
+#[test]
+fn test_swar() {
+  check::<Swar>();
+}
+
+#[cfg(feature = "simd")]
+#[test]
+fn test_simd() {
+  check::<crate::search::Simd>();
+}
+
+#[test]
+fn test_every_position() {
+  // Places a match at each offset to cover all lanes and block boundaries
+  let haystack = "................................................";
+
+  for i in 0..haystack.len() {
+    let mut bytes = [b'.'; 48];
+    bytes[i] = b'x';
+
+    for j in [i, i + 1, i + 17].into_iter().filter(|&j| j < 48) {
+      let mut bytes = bytes;
+      bytes[j] = b'x';
+      let s = core::str::from_utf8(&bytes).unwrap();
+
+      assert_eq!(find::<Swar>(&bytes, b"x"), s.find('x'));
+      assert_eq!(rfind::<Swar>(&bytes, b"x"), s.rfind('x'));
+      assert_eq!(BStr127::from(s).find("x"), s.find('x'));
+      assert_eq!(BStr127::from(s).rfind("x"), s.rfind('x'));
+    }
+  }
+}
+
+#[test]
+fn test_bounded_str() {
+  let s = BStr127::from("GET /api/v1/users?id=42&sort=asc HTTP/1.1");
+
+  assert_eq!(s.find("/"), Some(4));
+  assert_eq!(s.rfind("/"), Some(37));
+  assert_eq!(s.find("HTTP"), Some(33));
+  assert_eq!(s.find("http"), None);
+  assert!(s.contains("id=42"));
+  assert!(!s.contains("id=43"));
+  assert!(s.contains(""));
+  assert_eq!(s.matches("=").count(), 2);
+  assert!(s.matches("/").all(|m| m == "/"));
+
+  let s = BStr15::from("");
+  assert_eq!(s.find(""), Some(0));
+  assert_eq!(s.rfind(""), Some(0));
+  assert_eq!(s.matches("").count(), 1);
+}
+
+#[test]
+fn test_try_from_split() {
+  let vec = StrVec28::try_from_split("a::b:", ":").unwrap();
+  assert!(vec.iter().eq(["a", "", "b", ""]));
+
+  let vec = StrVec28::try_from_split("", ",").unwrap();
+  assert!(vec.iter().eq([""]));
+
+  assert_eq!(
+    StrVec::<u8, 8, ()>::try_from_split("abcd,efghi", ","),
+    Err(ExceedsCapacity {
+      length: 8 + 1,
+      capacity: 8,
+    })
+  );
+  assert_eq!(
+    StrVec::<u8, 8, ()>::try_from_split(",,,,,,,,", ","),
+    Err(ExceedsCapacity {
+      length: 9,
+      capacity: 8,
+    })
+  );
+}
+
+#[test]
+fn test_split_matches_push() {
+  let s = BStr127::from("2024-01-01 12:00:00 GET /index.html 200 1024");
+  let expected = StrVec::<u128, 127, crate::Align128>::try_from(
+    &[
+      "2024-01-01",
+      "12:00:00",
+      "GET",
+      "/index.html",
+      "200",
+      "1024",
+    ][..],
+  )
+  .unwrap();
+
+  assert_eq!(s.split(" "), expected);
+}
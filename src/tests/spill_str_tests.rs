@@ -0,0 +1,127 @@
+use core::hash::BuildHasher;
+use std::collections::hash_map::RandomState;
+use std::format;
+use std::string::String;
+
+use crate::BStr15;
+use crate::ExceedsCapacity;
+use crate::SpillStr15;
+
+#[test]
+fn test_inline() {
+  let s = SpillStr15::from("a".repeat(15).as_str());
+  assert!(s.is_inline());
+  assert_eq!(s.len(), 15);
+
+  let s = SpillStr15::from("a".repeat(16).as_str());
+  assert!(!s.is_inline());
+  assert_eq!(s.len(), 16);
+}
+
+#[test]
+fn test_push_str() {
+  let mut s = SpillStr15::new();
+  assert!(s.is_empty());
+
+  s.push_str("hello");
+  s.push(' ');
+  assert!(s.is_inline());
+
+  s.push_str("wonderful world");
+  assert!(!s.is_inline());
+  assert_eq!(s.as_str(), "hello wonderful world");
+
+  s.clear();
+  assert!(s.is_inline());
+  assert!(s.is_empty());
+}
+
+#[test]
+fn test_push_multi_byte() {
+  let mut s = SpillStr15::from("a".repeat(14).as_str());
+  s.push('€');
+
+  assert!(!s.is_inline());
+  assert_eq!(s.len(), 17);
+}
+
+#[test]
+fn test_from_string() {
+  let s = SpillStr15::from(String::from("short"));
+  assert!(s.is_inline());
+
+  let heap = "a".repeat(32);
+  let ptr = heap.as_ptr();
+  let s = SpillStr15::from(heap);
+  assert!(!s.is_inline());
+  assert_eq!(s.as_str().as_ptr(), ptr);
+  let s = String::from(s);
+  assert_eq!(s.as_ptr(), ptr);
+}
+
+#[test]
+fn test_bounded_str() {
+  let s = SpillStr15::from(BStr15::from("abc"));
+  assert_eq!(s.try_into(), Ok(BStr15::from("abc")));
+
+  let s = SpillStr15::from("a".repeat(20).as_str());
+  assert_eq!(
+    TryInto::<BStr15>::try_into(s),
+    Err(ExceedsCapacity {
+      length: 20,
+      capacity: 15
+    })
+  );
+}
+
+#[test]
+fn test_ord() {
+  let long = SpillStr15::from("a".repeat(20).as_str());
+
+  assert!(SpillStr15::from("a") < SpillStr15::from("b"));
+  assert!(SpillStr15::from("b") < SpillStr15::from("aa"));
+  assert!(SpillStr15::from("b") < long);
+  assert_eq!(
+    SpillStr15::from("b").cmp(&SpillStr15::from("aa")),
+    BStr15::from("b").cmp(&BStr15::from("aa"))
+  );
+}
+
+#[test]
+fn test_hash() {
+  let state = RandomState::new();
+
+  assert_eq!(
+    state.hash_one(SpillStr15::from("abc")),
+    state.hash_one("abc")
+  );
+  assert_eq!(
+    state.hash_one(SpillStr15::from("a".repeat(20).as_str())),
+    state.hash_one("a".repeat(20).as_str())
+  );
+}
+
+#[test]
+fn test_fmt() {
+  let s = SpillStr15::from("hello");
+  assert_eq!(format!("{s}"), "hello");
+  assert_eq!(format!("{s:?}"), "hello");
+}
+
+#[cfg(feature = "serde")]
+mod serde_tests {
+  use crate::SpillStr15;
+
+  #[test]
+  fn test_roundtrip() {
+    for input in ["short", "a string that spills to the heap"] {
+      let s = SpillStr15::from(input);
+      let json = serde_json::to_string(&s).unwrap();
+      assert_eq!(json, serde_json::to_string(input).unwrap());
+
+      let result = serde_json::from_str::<SpillStr15>(&json).unwrap();
+      assert_eq!(result, s);
+      assert_eq!(result.is_inline(), input.len() <= 15);
+    }
+  }
+}
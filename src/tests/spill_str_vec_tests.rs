@@ -0,0 +1,129 @@
+use core::hash::BuildHasher;
+use std::collections::hash_map::RandomState;
+use std::format;
+use std::string::String;
+use std::vec;
+use std::vec::Vec;
+
+use crate::ExceedsCapacity;
+use crate::SpillStrVec28;
+use crate::StrVec28;
+
+#[test]
+fn test_push() {
+  let mut vec = SpillStrVec28::new();
+  assert!(vec.is_empty());
+
+  vec.push("aws");
+  vec.push("");
+  vec.push("\0");
+  assert!(vec.is_inline());
+  assert_eq!(vec.to_vec(), vec!["aws", "", ""]);
+
+  vec.push(&"a".repeat(26));
+  assert!(!vec.is_inline());
+  assert_eq!(vec.len(), 4);
+  assert_eq!(vec.get(0), Some("aws"));
+  assert_eq!(vec.get(1), Some(""));
+  assert_eq!(vec.get(2), Some(""));
+  assert_eq!(vec.get(3), Some("a".repeat(26).as_str()));
+  assert_eq!(vec.get(4), None);
+
+  vec.push("\0");
+  assert_eq!(vec.get(4), Some(""));
+
+  vec.clear();
+  assert!(vec.is_inline());
+  assert!(vec.is_empty());
+}
+
+#[test]
+fn test_push_at_capacity() {
+  let mut vec = SpillStrVec28::new();
+  vec.push(&"a".repeat(28));
+  assert!(vec.is_inline());
+
+  vec.push("");
+  assert!(!vec.is_inline());
+  assert_eq!(vec.len(), 2);
+}
+
+#[test]
+fn test_from_vec() {
+  let vec = SpillStrVec28::from(vec![String::from("a"), String::from("b")]);
+  assert!(vec.is_inline());
+
+  let values = vec![String::from("\0"), "a".repeat(30)];
+  let vec = SpillStrVec28::from(values);
+  assert!(!vec.is_inline());
+  assert_eq!(vec.to_vec(), vec!["", "a".repeat(30).as_str()]);
+}
+
+#[test]
+fn test_str_vec() {
+  let inline = StrVec28::from(["a", "b"]);
+  let vec = SpillStrVec28::from(inline);
+  assert_eq!(vec.try_into(), Ok(inline));
+
+  let vec = SpillStrVec28::from(&["a", "", &"b".repeat(30)][..]);
+  assert_eq!(
+    TryInto::<StrVec28>::try_into(vec),
+    Err(ExceedsCapacity {
+      length: 32,
+      capacity: 28
+    })
+  );
+}
+
+#[test]
+fn test_eq_hash() {
+  let state = RandomState::new();
+
+  let mut inline = SpillStrVec28::new();
+  inline.push("a");
+  inline.push("b");
+
+  let mut spilled = SpillStrVec28::new();
+  spilled.push(&"x".repeat(30));
+  spilled.clear();
+  spilled.push("a");
+  spilled.push("b");
+
+  assert_eq!(inline, spilled);
+  assert_eq!(state.hash_one(&inline), state.hash_one(&spilled));
+  assert_ne!(inline, SpillStrVec28::from(&["ab"][..]));
+  assert_ne!(
+    state.hash_one(&inline),
+    state.hash_one(SpillStrVec28::from(&["ab"][..]))
+  );
+}
+
+#[test]
+fn test_iter() {
+  let items = ["alpha", "beta", "gamma", "delta", "epsilon", "zeta"];
+  let vec = SpillStrVec28::from(&items[..]);
+
+  assert!(!vec.is_inline());
+  assert_eq!(vec.iter().collect::<Vec<_>>(), items);
+  assert_eq!(format!("{vec:?}"), format!("{items:?}"));
+}
+
+#[cfg(feature = "serde")]
+mod serde_tests {
+  use std::vec;
+
+  use crate::SpillStrVec28;
+
+  #[test]
+  fn test_roundtrip() {
+    for items in [vec!["a", "b"], vec!["a"; 30]] {
+      let vec = SpillStrVec28::from(&items[..]);
+      let json = serde_json::to_string(&vec).unwrap();
+      assert_eq!(json, serde_json::to_string(&items).unwrap());
+
+      let result = serde_json::from_str::<SpillStrVec28>(&json).unwrap();
+      assert_eq!(result, vec);
+      assert_eq!(result.is_inline(), items.len() <= 28);
+    }
+  }
+}
@@ -4,7 +4,7 @@ use core::fmt::Write;
 use core::mem;
 
 use crate::{
-  ExceedsCapacity, StrVec, StrVec28, StrVec56, StrVec112, alignment::Align16,
+  ExceedsCapacity, InvalidBytes, StrVec, StrVec28, StrVec56, StrVec112, alignment::Align16,
   tests::writer_util::ByteMutWriter,
 };
 
@@ -22,6 +22,14 @@ fn test_alignment() {
   assert_eq!(mem::align_of::<StrVec112>(), 128);
 }
 
+#[test]
+fn test_layout() {
+  assert_eq!(mem::offset_of!(StrVec28, bitmap), 0);
+  assert_eq!(mem::offset_of!(StrVec28, data), 4);
+  assert_eq!(mem::offset_of!(StrVec56, data), 8);
+  assert_eq!(mem::offset_of!(StrVec112, data), 16);
+}
+
 #[test]
 fn test_type() {
   let _ = StrVec::<u16, 16, Align16>::new();
@@ -189,6 +197,55 @@ fn test_debug() {
   assert_eq!(buf.as_str(), r#"["a", "b", "c"]"#);
 }
 
+#[test]
+fn test_wire_format_golden() {
+  let golden = include_bytes!("golden/strvec28_v1.bin");
+  let v = StrVec28::try_from(["aws", "us", "", "é"]).unwrap();
+
+  assert_eq!(&v.to_bytes(), golden);
+  assert_eq!(StrVec28::try_from_bytes(golden), Ok(v));
+}
+
+#[test]
+fn test_wire_format_bitmap_endianness() {
+  let mut v = StrVec112::new();
+  v.push("a").unwrap();
+
+  let bytes: [u8; 128] = v.to_bytes();
+
+  // The first item's end bit is the most significant bit of the bitmap
+  assert_eq!(&bytes[..16], &(1u128 << 127).to_le_bytes());
+  assert_eq!(bytes[16], b'a');
+}
+
+#[test]
+fn test_try_from_bytes_invalid() {
+  let mut bytes: [u8; 32] = StrVec28::try_from(["é"]).unwrap().to_bytes();
+
+  // Split the multi-byte character into two items
+  bytes[3] |= 0b1000_0000;
+  assert_eq!(
+    StrVec28::try_from_bytes(&bytes),
+    Err(InvalidBytes::InvalidUtf8 { offset: 4 })
+  );
+
+  // Mark a position beyond the capacity
+  let mut bytes = [0u8; 32];
+  bytes[0] = 0b1000;
+  assert_eq!(
+    StrVec28::try_from_bytes(&bytes),
+    Err(InvalidBytes::InvalidBitmap)
+  );
+
+  // Data after the last item
+  let mut bytes = [0u8; 32];
+  bytes[31] = b'x';
+  assert_eq!(
+    StrVec28::try_from_bytes(&bytes),
+    Err(InvalidBytes::NonZeroPadding { offset: 31 })
+  );
+}
+
 #[cfg(feature = "std")]
 mod std {
   use std::collections::HashSet;
@@ -346,3 +403,530 @@ mod serde_tests {
     assert_eq!(result, StrVec56::try_from(["admin"]).unwrap());
   }
 }
+
+#[cfg(all(feature = "rkyv", feature = "std"))]
+mod rkyv_tests {
+  use rkyv::rancor::Error;
+  use rkyv::util::AlignedVec;
+  use std::vec::Vec;
+
+  use crate::{ArchivedStrVec, StrVec28, StrVec56};
+
+  type ArchivedStrVec28 = ArchivedStrVec<u32, 28, crate::Align32>;
+
+  #[test]
+  fn test_archive_layout() {
+    let v = StrVec28::try_from(["a", "", "b"]).unwrap();
+    let bytes = rkyv::api::high::to_bytes_in::<_, Error>(&v, AlignedVec::<32>::new()).unwrap();
+
+    // Bitmap is stored in little-endian byte order
+    assert_eq!(
+      &bytes[..4],
+      &0b1110_0000_0000_0000_0000_0000_0000_0000u32.to_le_bytes()
+    );
+    assert_eq!(
+      &bytes[4..],
+      b"a\0b\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0"
+    );
+  }
+
+  #[test]
+  fn test_access() {
+    let v = StrVec56::try_from(["us", "", "east", "\u{1F600}"]).unwrap();
+    let bytes = rkyv::api::high::to_bytes_in::<_, Error>(&v, AlignedVec::<64>::new()).unwrap();
+
+    let archived = rkyv::access::<rkyv::Archived<StrVec56>, Error>(&bytes).unwrap();
+    assert_eq!(archived, &v);
+    assert_eq!(archived.len(), 4);
+    assert_eq!(archived.get(2), Some("east"));
+    assert_eq!(archived.get(4), None);
+    assert_eq!(archived.iter().collect::<Vec<_>>(), v.to_vec());
+
+    let deserialised = rkyv::deserialize::<StrVec56, Error>(archived).unwrap();
+    assert_eq!(deserialised, v);
+  }
+
+  #[test]
+  fn test_access_invalid_bitmap() {
+    // Bit 30 lies beyond the capacity of 28
+    let mut bytes = AlignedVec::<32>::new();
+    bytes.extend_from_slice(&0b10u32.to_le_bytes());
+    bytes.extend_from_slice(&[0; 28]);
+
+    assert!(rkyv::access::<ArchivedStrVec28, Error>(&bytes).is_err());
+  }
+
+  #[test]
+  fn test_access_invalid_utf8() {
+    // A multi-byte character must not be split across two items
+    let mut bytes = AlignedVec::<32>::new();
+    bytes.extend_from_slice(&0b1100_0000_0000_0000_0000_0000_0000_0000u32.to_le_bytes());
+    bytes.extend_from_slice("é".as_bytes());
+    bytes.extend_from_slice(&[0; 26]);
+
+    assert!(rkyv::access::<ArchivedStrVec28, Error>(&bytes).is_err());
+  }
+
+  #[test]
+  fn test_access_non_zero_padding() {
+    let mut bytes = AlignedVec::<32>::new();
+    bytes.extend_from_slice(&0b1000_0000_0000_0000_0000_0000_0000_0000u32.to_le_bytes());
+    bytes.extend_from_slice(b"ab");
+    bytes.extend_from_slice(&[0; 26]);
+
+    assert!(rkyv::access::<ArchivedStrVec28, Error>(&bytes).is_err());
+  }
+}
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck_tests {
+  use crate::StrVec28;
+
+  #[test]
+  fn test_layout() {
+    let v = StrVec28::try_from(["a", "", "b"]).unwrap();
+    let bytes = bytemuck::bytes_of(&v);
+
+    // Bitmap is stored in native byte order
+    assert_eq!(
+      &bytes[..4],
+      &0b1110_0000_0000_0000_0000_0000_0000_0000u32.to_ne_bytes()
+    );
+    assert_eq!(
+      &bytes[4..],
+      b"a\0b\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0"
+    );
+  }
+
+  #[test]
+  fn test_round_trip() {
+    let v = StrVec28::try_from(["us", "east", "\u{1F600}"]).unwrap();
+    let bytes = bytemuck::bytes_of(&v);
+
+    assert_eq!(bytemuck::checked::try_from_bytes::<StrVec28>(bytes), Ok(&v));
+  }
+
+  #[test]
+  fn test_invalid_bitmap() {
+    let mut v = StrVec28::new();
+    v.bitmap = 0b10;
+
+    let bytes = bytemuck::bytes_of(&v);
+    assert!(bytemuck::checked::try_from_bytes::<StrVec28>(bytes).is_err());
+  }
+}
+
+#[cfg(feature = "zerocopy")]
+mod zerocopy_tests {
+  use zerocopy::IntoBytes;
+
+  use crate::{InvalidBytes, StrVec, StrVec28};
+
+  #[test]
+  fn test_layout() {
+    let v = StrVec::<u8, 7, ()>::try_from(["ab", "c"]).unwrap();
+    assert_eq!(v.as_bytes(), b"\x60abc\0\0\0\0");
+  }
+
+  #[test]
+  fn test_try_slice_from_bytes() {
+    #[repr(C, align(32))]
+    struct Buffer([u8; 64]);
+
+    let mut buffer = Buffer([0; 64]);
+    buffer.0[..4].copy_from_slice(&(1u32 << 31 | 1 << 29).to_ne_bytes());
+    buffer.0[4..7].copy_from_slice(b"abc");
+    buffer.0[32..36].copy_from_slice(&(1u32 << 31).to_ne_bytes());
+    buffer.0[36] = b'x';
+
+    let items = StrVec28::try_slice_from_bytes(&buffer.0).unwrap();
+    assert!(items[0].iter().eq(["a", "bc"]));
+    assert!(items[1].iter().eq(["x"]));
+
+    assert_eq!(StrVec28::try_ref_from_bytes(&buffer.0[..32]), Ok(&items[0]));
+    assert_eq!(
+      StrVec28::try_slice_from_bytes(&buffer.0[..48]),
+      Err(InvalidBytes::Layout)
+    );
+  }
+
+  #[test]
+  fn test_try_ref_from_bytes_invalid() {
+    #[repr(C, align(32))]
+    struct Buffer([u8; 32]);
+
+    let mut buffer = Buffer([0; 32]);
+    buffer.0[..4].copy_from_slice(&(1u32 << 31).to_ne_bytes());
+    buffer.0[4] = b'x';
+    buffer.0[8] = b'y';
+
+    assert_eq!(
+      StrVec28::try_ref_from_bytes(&buffer.0),
+      Err(InvalidBytes::NonZeroPadding { offset: 4 })
+    );
+
+    buffer.0[..4].copy_from_slice(&1u32.to_ne_bytes());
+    assert_eq!(
+      StrVec28::try_ref_from_bytes(&buffer.0),
+      Err(InvalidBytes::InvalidBitmap)
+    );
+  }
+}
+
+#[cfg(all(feature = "borsh", feature = "std"))]
+mod borsh_tests {
+  use std::string::{String, ToString};
+  use std::vec;
+  use std::vec::Vec;
+
+  use crate::{StrVec28, StrVec56};
+
+  #[test]
+  fn test_round_trip() {
+    let v = StrVec56::try_from(["aws", "", "east", "\u{1F600}"]).unwrap();
+    let bytes = borsh::to_vec(&v).unwrap();
+
+    let strings: Vec<String> = v.iter().map(|s| s.to_string()).collect();
+    assert_eq!(bytes, borsh::to_vec(&strings).unwrap());
+    assert_eq!(borsh::from_slice::<StrVec56>(&bytes).unwrap(), v);
+  }
+
+  #[test]
+  fn test_exceeds_capacity() {
+    let strings = vec!["a".repeat(20), "b".repeat(9)];
+    let bytes = borsh::to_vec(&strings).unwrap();
+
+    assert!(borsh::from_slice::<StrVec28>(&bytes).is_err());
+  }
+}
+
+#[cfg(all(feature = "bincode", feature = "std"))]
+mod bincode_tests {
+  use std::string::{String, ToString};
+  use std::vec;
+  use std::vec::Vec;
+
+  use crate::{StrVec28, StrVec56};
+
+  #[test]
+  fn test_round_trip() {
+    let config = bincode::config::standard();
+    let v = StrVec56::try_from(["aws", "", "east", "\u{1F600}"]).unwrap();
+    let bytes = bincode::encode_to_vec(v, config).unwrap();
+
+    let strings: Vec<String> = v.iter().map(|s| s.to_string()).collect();
+    assert_eq!(bytes, bincode::encode_to_vec(&strings, config).unwrap());
+    assert_eq!(
+      bincode::decode_from_slice::<StrVec56, _>(&bytes, config).unwrap(),
+      (v, bytes.len())
+    );
+  }
+
+  #[test]
+  fn test_exceeds_capacity() {
+    let config = bincode::config::standard();
+    let strings = vec!["a".repeat(20), "b".repeat(9)];
+    let bytes = bincode::encode_to_vec(&strings, config).unwrap();
+
+    assert!(bincode::decode_from_slice::<StrVec28, _>(&bytes, config).is_err());
+  }
+}
+
+#[cfg(feature = "postcard")]
+mod postcard_tests {
+  use postcard::experimental::max_size::MaxSize;
+
+  use crate::{StrVec28, StrVec112};
+
+  #[test]
+  fn test_max_size() {
+    assert_eq!(StrVec28::POSTCARD_MAX_SIZE, 1 + 2 * 28);
+    assert_eq!(StrVec112::POSTCARD_MAX_SIZE, 1 + 2 * 112);
+  }
+
+  #[cfg(feature = "serde")]
+  #[test]
+  fn test_max_size_worst_case() {
+    let mut buf = [0u8; StrVec112::POSTCARD_MAX_SIZE];
+
+    let mut v = StrVec112::new();
+    for _ in 0..112 {
+      v.push("a").unwrap();
+    }
+
+    let encoded = postcard::to_slice(&v, &mut buf).unwrap();
+    assert_eq!(encoded.len(), StrVec112::POSTCARD_MAX_SIZE);
+  }
+
+  #[cfg(feature = "serde")]
+  #[test]
+  fn test_max_size_bounds_other_encodings() {
+    let mut buf = [0u8; StrVec28::POSTCARD_MAX_SIZE];
+
+    let cases: [&[&str]; 4] = [
+      &[""; 28],
+      &["aaaaaaaaaaaaaaaaaaaaaaaaaaaa"],
+      &["aa"; 14],
+      &["é", "", "ab", "\u{1F600}", "", "abcdefghijklmn"],
+    ];
+
+    for items in cases {
+      let v = StrVec28::try_from(items).unwrap();
+      assert!(postcard::to_slice(&v, &mut buf).is_ok());
+    }
+  }
+}
+
+#[cfg(feature = "schemars")]
+mod schemars_tests {
+  use schemars::{JsonSchema, SchemaGenerator, json_schema};
+
+  use crate::StrVec28;
+
+  #[test]
+  fn test_schema() {
+    let schema = StrVec28::json_schema(&mut SchemaGenerator::default());
+    assert_eq!(
+      schema,
+      json_schema!({
+        "type": "array",
+        "items": {
+          "type": "string",
+          "maxLength": 28,
+        },
+        "maxItems": 28,
+        "description": "Items must not exceed 28 UTF-8 bytes in total, where empty items take up 1 byte",
+      })
+    );
+  }
+}
+
+#[cfg(all(feature = "proptest", feature = "std"))]
+mod proptest_tests {
+  use std::vec::Vec;
+
+  use proptest::prelude::*;
+  use proptest::strategy::ValueTree;
+  use proptest::test_runner::TestRunner;
+
+  use crate::StrVec28;
+
+  /// Number of bytes occupied by the items
+  fn occupied(v: &StrVec28) -> usize {
+    v.iter().map(|s| s.len().max(1)).sum()
+  }
+
+  proptest! {
+    #[test]
+    fn test_wire_format_roundtrip(v: StrVec28) {
+      let bytes: [u8; 32] = v.to_bytes();
+      prop_assert_eq!(StrVec28::try_from_bytes(&bytes), Ok(v));
+    }
+
+    #[test]
+    fn test_items_roundtrip(v: StrVec28) {
+      let items: Vec<&str> = v.iter().collect();
+      prop_assert!(occupied(&v) <= 28);
+      prop_assert_eq!(StrVec28::try_from(&items[..]).unwrap(), v);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_roundtrip(v: StrVec28) {
+      let json = serde_json::to_string(&v).unwrap();
+      prop_assert_eq!(serde_json::from_str::<StrVec28>(&json).unwrap(), v);
+    }
+  }
+
+  #[test]
+  fn test_edge_cases() {
+    let mut runner = TestRunner::deterministic();
+    let strategy = any::<StrVec28>();
+
+    let values: Vec<StrVec28> = (0..1000)
+      .map(|_| strategy.new_tree(&mut runner).unwrap().current())
+      .collect();
+
+    assert!(values.iter().any(|v| v.is_empty()));
+    assert!(values.iter().any(|v| v.iter().any(str::is_empty)));
+    assert!(values.iter().any(|v| v.iter().any(|s| s.contains('\0'))));
+    assert!(values.iter().any(|v| occupied(v) == 28));
+    assert!(values.iter().any(|v| {
+      occupied(v) == 28 && v.iter().last().unwrap().chars().last().unwrap().len_utf8() > 1
+    }));
+  }
+}
+
+#[cfg(all(feature = "quickcheck", feature = "std"))]
+mod quickcheck_tests {
+  use quickcheck::{Arbitrary, Gen, quickcheck};
+
+  use crate::StrVec28;
+
+  #[test]
+  fn test_wire_format_roundtrip() {
+    fn prop(v: StrVec28) -> bool {
+      let bytes: [u8; 32] = v.to_bytes();
+      StrVec28::try_from_bytes(&bytes) == Ok(v)
+    }
+
+    quickcheck(prop as fn(StrVec28) -> bool);
+  }
+
+  #[test]
+  fn test_shrink() {
+    let mut g = Gen::new(100);
+
+    for _ in 0..100 {
+      let v = StrVec28::arbitrary(&mut g);
+
+      for shrunk in v.shrink() {
+        assert!(shrunk.len() <= v.len());
+      }
+    }
+  }
+}
+
+#[cfg(all(feature = "arbitrary", feature = "std"))]
+mod arbitrary_tests {
+  use std::vec::Vec;
+
+  use arbitrary::{Arbitrary, Unstructured};
+
+  use crate::StrVec28;
+
+  #[test]
+  fn test_wire_format_roundtrip() {
+    let data: Vec<u8> = (0..4096u32)
+      .map(|i| (i.wrapping_mul(2654435761) >> 13) as u8)
+      .collect();
+    let mut u = Unstructured::new(&data);
+
+    while !u.is_empty() {
+      let v = StrVec28::arbitrary(&mut u).unwrap();
+
+      let bytes: [u8; 32] = v.to_bytes();
+      assert_eq!(StrVec28::try_from_bytes(&bytes), Ok(v));
+    }
+  }
+}
+
+#[cfg(feature = "ufmt")]
+mod ufmt_tests {
+  use ufmt::uwrite;
+
+  use crate::{BStr63, StrVec28};
+
+  #[test]
+  fn test_debug() {
+    let v = StrVec28::try_from(["us", "", "é", "a\"b", "it's", "\0\n"]).unwrap();
+    let mut out = BStr63::new();
+
+    uwrite!(out, "{:?}", v).unwrap();
+    assert_eq!(out.as_str(), r#"["us", "", "é", "a\"b", "it's", "\0\n"]"#);
+  }
+
+  #[cfg(feature = "std")]
+  #[test]
+  fn test_debug_matches_fmt() {
+    let v = StrVec28::try_from(["us", "", "é", "a\"b", "it's", "\0\n"]).unwrap();
+    let mut out = BStr63::new();
+
+    uwrite!(out, "{:?}", v).unwrap();
+    assert_eq!(out.as_str(), std::format!("{:?}", v));
+  }
+
+  #[test]
+  fn test_debug_empty() {
+    let mut out = BStr63::new();
+
+    uwrite!(out, "{:?}", StrVec28::new()).unwrap();
+    assert_eq!(out.as_str(), "[]");
+  }
+}
+
+#[cfg(feature = "defmt")]
+mod defmt_tests {
+  use crate::StrVec28;
+
+  fn assert_format<T: defmt::Format>() {}
+
+  #[test]
+  fn test_format() {
+    assert_format::<StrVec28>();
+  }
+}
+
+#[cfg(feature = "heapless")]
+mod heapless_tests {
+  use crate::{Align8, ExceedsCapacity, StrVec, StrVec28};
+
+  type Strings<const M: usize, const K: usize> = heapless::Vec<heapless::String<M>, K>;
+
+  fn strings<const M: usize, const K: usize>(values: &[&str]) -> Strings<M, K> {
+    values
+      .iter()
+      .map(|v| heapless::String::try_from(*v).unwrap())
+      .collect()
+  }
+
+  #[test]
+  fn test_from_heapless() {
+    let values: Strings<4, 7> = strings(&["us", "", "aws", "é"]);
+    let v = StrVec28::from(values);
+
+    assert_eq!(v, StrVec28::try_from(["us", "", "aws", "é"]).unwrap());
+  }
+
+  #[test]
+  fn test_from_heapless_empty_items() {
+    let values: Strings<0, 8> = strings(&[""; 8]);
+    let v = StrVec::<u8, 8, Align8>::from(values);
+
+    assert_eq!(v.len(), 8);
+  }
+
+  #[test]
+  fn test_into_heapless() {
+    let v = StrVec::<u8, 8, Align8>::try_from(["a", "", "bcd"]).unwrap();
+    let values: Strings<8, 8> = v.into();
+
+    assert_eq!(values, strings::<8, 8>(&["a", "", "bcd"]));
+  }
+
+  #[test]
+  fn test_try_from_heapless_slice() {
+    let values: Strings<16, 4> = strings(&["abcdefghijklmnop", "abcdefghijklmnop"]);
+
+    assert_eq!(
+      StrVec28::try_from(values.as_slice()),
+      Err(ExceedsCapacity {
+        length: 32,
+        capacity: 28
+      })
+    );
+  }
+
+  #[test]
+  fn test_try_heapless() {
+    let values: Strings<16, 4> = strings(&["abc", "d"]);
+    let v = StrVec28::try_from_heapless(&values).unwrap();
+    assert!(v.iter().eq(["abc", "d"]));
+
+    assert_eq!(v.try_into_heapless::<4, 2>(), Ok(strings(&["abc", "d"])));
+    assert_eq!(
+      v.try_into_heapless::<2, 2>(),
+      Err(ExceedsCapacity {
+        length: 3,
+        capacity: 2
+      })
+    );
+    assert_eq!(
+      v.try_into_heapless::<4, 1>(),
+      Err(ExceedsCapacity {
+        length: 2,
+        capacity: 1
+      })
+    );
+  }
+}
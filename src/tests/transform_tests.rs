@@ -0,0 +1,166 @@
+use crate::{
+  Align8, Align16, BStr7, BStr15, BStr31, BoundedStr, ExceedsCapacity, FStr8, FStr16, FixedStr,
+  Pad, RightSpace, Space,
+};
+
+#[test]
+fn test_bounded_str_trim() {
+  let s = BStr15::from(" \t key = 1 \n");
+  assert_eq!(s.trim().as_str(), "key = 1");
+  assert_eq!(s.trim_start().as_str(), "key = 1 \n");
+  assert_eq!(s.trim_end().as_str(), " \t key = 1");
+  assert_eq!(BStr15::from("\u{3000}a\u{a0}").trim().as_str(), "a");
+
+  // Unused bytes stay NUL, so the result equals a freshly constructed value
+  assert_eq!(s.trim(), BStr15::from("key = 1"));
+  assert_eq!(BStr7::from("   ").trim(), BStr7::new());
+}
+
+#[test]
+fn test_bounded_str_case() {
+  let s = BStr31::from("Content-Type: ÄÖü/HTML");
+  assert_eq!(s.to_ascii_lowercase().as_str(), "content-type: ÄÖü/html");
+  assert_eq!(s.to_ascii_uppercase().as_str(), "CONTENT-TYPE: ÄÖü/HTML");
+  assert_eq!(s.as_str(), "Content-Type: ÄÖü/HTML");
+}
+
+#[test]
+fn test_bounded_str_replace() {
+  let s = BStr15::from("a-b-c");
+
+  let r: BStr15 = s.replace("-", "").unwrap();
+  assert_eq!(r.as_str(), "abc");
+  assert_eq!(r, BStr15::from("abc"));
+
+  let r: BStr7 = s.replace("b", "xy").unwrap();
+  assert_eq!(r.as_str(), "a-xy-c");
+
+  let r: BStr15 = s.replace("missing", "x").unwrap();
+  assert_eq!(r, s);
+
+  let r: BStr15 = BStr7::from("ab").replace("", "|").unwrap();
+  assert_eq!(r.as_str(), "|a|b|");
+
+  let r: Result<BStr7, _> = s.replace("-", "---");
+  assert_eq!(
+    r,
+    Err(ExceedsCapacity {
+      length: 7 + 2,
+      capacity: 7,
+    })
+  );
+}
+
+#[test]
+fn test_bounded_str_repeat() {
+  let s = BStr7::from("ab");
+
+  let r: BStr15 = s.repeat(3).unwrap();
+  assert_eq!(r.as_str(), "ababab");
+
+  let r: BStr7 = s.repeat(0).unwrap();
+  assert!(r.is_empty());
+
+  let r: BStr7 = BStr7::new().repeat(100).unwrap();
+  assert!(r.is_empty());
+
+  let r: Result<BoundedStr<5, Align8>, _> = s.repeat(3);
+  assert_eq!(
+    r,
+    Err(ExceedsCapacity {
+      length: 6,
+      capacity: 5,
+    })
+  );
+
+  let r: Result<BStr7, _> = s.repeat(usize::MAX);
+  assert!(r.is_err());
+}
+
+#[test]
+fn test_fixed_str_trim() {
+  let s = FStr16::from("  a b  ");
+  assert_eq!(s.trim(), FStr16::from("a b"));
+  assert_eq!(s.trim_start(), FStr16::from("a b  "));
+  assert_eq!(s.trim_end(), FStr16::from("  a b"));
+
+  // Only the content is trimmed, the padding is restored
+  let s = FixedStr::<8, Align8, RightSpace>::from("\t42");
+  assert_eq!(s.trim().as_str(), "      42");
+}
+
+#[test]
+fn test_fixed_str_case() {
+  let s = FStr8::from("Ab");
+  assert_eq!(s.to_ascii_uppercase(), FStr8::from("AB"));
+  assert_eq!(s.to_ascii_lowercase(), FStr8::from("ab"));
+
+  // The padding byte is not part of the content
+  let s = FixedStr::<8, Align8, Pad<b'X'>>::from("aBc");
+  assert_eq!(s.to_ascii_lowercase().as_str(), "abcXXXXX");
+  assert_eq!(s.to_ascii_uppercase().as_str(), "ABCXXXXX");
+}
+
+#[test]
+fn test_fixed_str_replace_repeat() {
+  let s = FixedStr::<8, Align8, Space>::from("1,000");
+
+  let r: FixedStr<8, Align8, Space> = s.replace(",", "").unwrap();
+  assert_eq!(r.as_str(), "1000    ");
+
+  let r: FixedStr<16, Align16, Space> = s.replace(",", ".").unwrap();
+  assert_eq!(r.as_str(), "1.000           ");
+
+  let r: FixedStr<16, Align16, Space> = s.repeat(2).unwrap();
+  assert_eq!(r.as_str(), "1,0001,000      ");
+
+  let r: Result<FixedStr<8, Align8, Space>, _> = s.repeat(2);
+  assert_eq!(
+    r,
+    Err(ExceedsCapacity {
+      length: 10,
+      capacity: 8,
+    })
+  );
+}
+
+#[cfg(feature = "std")]
+mod std_tests {
+  use super::*;
+
+  #[test]
+  fn test_matches_str() {
+    let haystacks = ["", "aaa", "a,b,,c", "äöü ä", "abcabcabc", "🦀x🦀"];
+    let patterns = ["", "a", ",", "ä", "abc", "🦀", "x🦀"];
+    let replacements = ["", "-", "::", "ß"];
+
+    for s in haystacks {
+      let bounded = BStr31::from(s);
+      let fixed = FixedStr::<24, Align8, Space>::from(s);
+
+      for from in patterns {
+        for to in replacements {
+          let expected = s.replace(from, to);
+
+          let r: BoundedStr<64, Align8> = bounded.replace(from, to).unwrap();
+          assert_eq!(
+            r.as_str(),
+            expected,
+            "{:?}.replace({:?}, {:?})",
+            s,
+            from,
+            to
+          );
+
+          let r: FixedStr<64, Align8, Space> = fixed.replace(from, to).unwrap();
+          assert_eq!(r.trimmed(), expected.trim_end_matches(' '));
+        }
+      }
+
+      for n in 0..4 {
+        let r: BoundedStr<96, Align8> = bounded.repeat(n).unwrap();
+        assert_eq!(r.as_str(), s.repeat(n));
+      }
+    }
+  }
+}
@@ -0,0 +1,169 @@
+use crate::{
+  Align16, Align128, AsciiAlphanumeric, BStr15, DnsLabel, ExceedsCapacity, Identifier, InvalidStr,
+  Lowercase, ValidatedStr, ValidationError, Validator,
+};
+
+type Alnum = ValidatedStr<AsciiAlphanumeric, 15, Align16>;
+type Ident = ValidatedStr<Identifier, 15, Align16>;
+type Label = ValidatedStr<DnsLabel, 127, Align128>;
+type Lower = ValidatedStr<Lowercase, 15, Align16>;
+
+const LONG: &str = "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+
+const fn invalid(offset: usize) -> ValidationError<InvalidStr> {
+  ValidationError::Invalid(InvalidStr { offset })
+}
+
+#[test]
+fn test_try_from() {
+  let s = Alnum::try_from("abc123").unwrap();
+  assert_eq!(s.as_str(), "abc123");
+  assert_eq!(s.len(), 6);
+  assert!(!s.is_empty());
+
+  assert_eq!(Alnum::try_from("abc-123"), Err(invalid(3)));
+  assert_eq!(
+    Alnum::try_from("abcdefghijklmnop"),
+    Err(ValidationError::ExceedsCapacity(ExceedsCapacity {
+      length: 16,
+      capacity: 15,
+    }))
+  );
+
+  let bounded = BStr15::from("abc");
+  let s: Alnum = bounded.try_into().unwrap();
+  assert_eq!(BStr15::from(s), bounded);
+
+  assert_eq!("x1".parse::<Alnum>().unwrap().as_str(), "x1");
+}
+
+#[test]
+fn test_push_revalidates() {
+  let mut s = Ident::try_from("foo").unwrap();
+  s.push('_').unwrap();
+  s.push_str("bar2").unwrap();
+  assert_eq!(s.as_str(), "foo_bar2");
+
+  assert_eq!(s.push('-'), Err(invalid(8)));
+  assert_eq!(s.push_str("ü"), Err(invalid(8)));
+  assert!(matches!(
+    s.push_str("12345678"),
+    Err(ValidationError::ExceedsCapacity(_))
+  ));
+  assert_eq!(s.as_str(), "foo_bar2");
+
+  assert_eq!(s.set("1abc"), Err(invalid(0)));
+  assert_eq!(s.as_str(), "foo_bar2");
+  s.set("_abc").unwrap();
+  assert_eq!(s.as_str(), "_abc");
+}
+
+#[test]
+fn test_ascii_alphanumeric() {
+  assert!(AsciiAlphanumeric::validate("Az09").is_ok());
+  assert_eq!(
+    AsciiAlphanumeric::validate(""),
+    Err(InvalidStr { offset: 0 })
+  );
+  assert_eq!(
+    AsciiAlphanumeric::validate("ab c"),
+    Err(InvalidStr { offset: 2 })
+  );
+  assert_eq!(
+    AsciiAlphanumeric::validate("aé"),
+    Err(InvalidStr { offset: 1 })
+  );
+}
+
+#[test]
+fn test_identifier() {
+  for s in ["a", "_", "_1", "snake_case", "CamelCase9"] {
+    assert!(Identifier::validate(s).is_ok(), "{}", s);
+  }
+
+  assert_eq!(Identifier::validate(""), Err(InvalidStr { offset: 0 }));
+  assert_eq!(
+    Identifier::validate("9lives"),
+    Err(InvalidStr { offset: 0 })
+  );
+  assert_eq!(
+    Identifier::validate("kebab-case"),
+    Err(InvalidStr { offset: 5 })
+  );
+}
+
+#[test]
+fn test_dns_label() {
+  for s in ["a", "0", "eu-west-1", "xn--bcher-kva", LONG] {
+    assert!(DnsLabel::validate(s).is_ok(), "{}", s);
+  }
+
+  assert_eq!(DnsLabel::validate(""), Err(InvalidStr { offset: 0 }));
+  assert_eq!(DnsLabel::validate("-a"), Err(InvalidStr { offset: 0 }));
+  assert_eq!(DnsLabel::validate("a-"), Err(InvalidStr { offset: 1 }));
+  assert_eq!(DnsLabel::validate("-"), Err(InvalidStr { offset: 0 }));
+  assert_eq!(DnsLabel::validate("a.b"), Err(InvalidStr { offset: 1 }));
+
+  let mut label = Label::try_from(LONG).unwrap();
+  assert_eq!(label.push('a'), Err(invalid(63)));
+}
+
+#[test]
+fn test_lowercase() {
+  assert!(Lowercase::validate("").is_ok());
+  assert!(Lowercase::validate("hello, wörld!").is_ok());
+  assert_eq!(Lowercase::validate("helLo"), Err(InvalidStr { offset: 3 }));
+
+  let mut s = Lower::try_from("abc").unwrap();
+  assert_eq!(s.push('D'), Err(invalid(3)));
+}
+
+#[test]
+fn test_const_validate() {
+  const _: () = assert!(Identifier::validate("const_ok").is_ok());
+  const _: () = assert!(DnsLabel::validate("-").is_err());
+
+  const IDENT: Ident = Ident::const_from("const_ok");
+  const LOWER: Lower = Lower::const_from("abc");
+  assert_eq!(IDENT, Ident::try_from("const_ok").unwrap());
+  assert_eq!(LOWER.as_str(), "abc");
+}
+
+#[test]
+fn test_custom_validator() {
+  struct Even;
+
+  impl Validator for Even {
+    type Error = usize;
+
+    fn validate(s: &str) -> Result<(), usize> {
+      if s.len().is_multiple_of(2) {
+        Ok(())
+      } else {
+        Err(s.len())
+      }
+    }
+  }
+
+  let mut s = ValidatedStr::<Even, 15, Align16>::try_from("ab").unwrap();
+  assert_eq!(s.push('c'), Err(ValidationError::Invalid(3)));
+  s.push_str("cd").unwrap();
+  assert_eq!(s.as_str(), "abcd");
+}
+
+#[cfg(feature = "serde")]
+mod serde_tests {
+  use super::*;
+
+  #[test]
+  fn test_serde() {
+    let s = Ident::try_from("foo_bar").unwrap();
+    let json = serde_json::to_string(&s).unwrap();
+    assert_eq!(json, "\"foo_bar\"");
+    assert_eq!(serde_json::from_str::<Ident>(&json).unwrap(), s);
+
+    assert!(serde_json::from_str::<Ident>("\"foo-bar\"").is_err());
+    assert!(serde_json::from_str::<Ident>("\"foo_bar_baz_qux\"").is_ok());
+    assert!(serde_json::from_str::<Ident>("\"foo_bar_baz_quux\"").is_err());
+  }
+}
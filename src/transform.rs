@@ -0,0 +1,63 @@
+//! Transformations that write their result into a fixed-size buffer
+
+use crate::ExceedsCapacity;
+use crate::search::{MatchIndices, Native};
+
+/// Copies `s` into `out`, replacing all occurrences of `from` with `to`
+///
+/// Returns the length of the result, or `Err` with the full length if it does
+/// not fit into `out`.
+pub(crate) fn replace_into(
+  s: &str,
+  from: &str,
+  to: &str,
+  out: &mut [u8],
+) -> Result<usize, ExceedsCapacity> {
+  let count = MatchIndices::<Native>::new(s, from).count();
+  let length = (s.len() - count * from.len()).saturating_add(count.saturating_mul(to.len()));
+
+  if length > out.len() {
+    return Err(ExceedsCapacity {
+      length,
+      capacity: out.len(),
+    });
+  }
+
+  let mut offset = 0;
+  let mut start = 0;
+  let mut write = |bytes: &[u8]| {
+    out[offset..offset + bytes.len()].copy_from_slice(bytes);
+    offset += bytes.len();
+  };
+
+  for i in MatchIndices::<Native>::new(s, from) {
+    write(&s.as_bytes()[start..i]);
+    write(to.as_bytes());
+    start = i + from.len();
+  }
+
+  write(&s.as_bytes()[start..]);
+
+  Ok(length)
+}
+
+/// Copies `s` into `out` `n` times
+///
+/// Returns the length of the result, or `Err` with the full length if it does
+/// not fit into `out`.
+pub(crate) fn repeat_into(s: &str, n: usize, out: &mut [u8]) -> Result<usize, ExceedsCapacity> {
+  let length = s.len().saturating_mul(n);
+
+  if length > out.len() {
+    return Err(ExceedsCapacity {
+      length,
+      capacity: out.len(),
+    });
+  }
+
+  for chunk in out[..length].chunks_exact_mut(s.len().max(1)) {
+    chunk.copy_from_slice(s.as_bytes());
+  }
+
+  Ok(length)
+}
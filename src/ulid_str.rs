@@ -0,0 +1,196 @@
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+
+#[cfg(feature = "serde")]
+use std::string::String;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::Align8;
+use crate::DecodeError;
+use crate::FixedStr;
+
+/// Crockford's base32 alphabet, which is in ASCII order
+const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Value of the Crockford base32 digit `c`, accepting lowercase letters as
+/// well as the aliases `I`, `L` (1) and `O` (0)
+const fn crockford_value(c: u8) -> Option<u8> {
+  let c = c.to_ascii_uppercase();
+
+  match c {
+    b'O' => Some(0),
+    b'I' | b'L' => Some(1),
+    _ => {
+      let mut i = 0;
+
+      while i < ALPHABET.len() {
+        if ALPHABET[i] == c {
+          return Some(i as u8);
+        }
+
+        i += 1;
+      }
+
+      None
+    }
+  }
+}
+
+/// Validated ULID in its canonical form of 26 Crockford base32 characters,
+/// e.g. `01ARZ3NDEKTSV4RRFFQ69G5FAV`
+///
+/// The string is always uppercase, such that the lexicographic order matches
+/// the ULID's numeric and thus chronological order.
+///
+/// # Usage
+/// ```rust
+/// # use qstr::UlidStr;
+/// let id = UlidStr::parse("01arz3ndektsv4rrffq69g5fav").unwrap();
+/// assert_eq!(id.as_str(), "01ARZ3NDEKTSV4RRFFQ69G5FAV");
+/// assert_eq!(UlidStr::from_bytes(&id.to_bytes()), id);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct UlidStr(FixedStr<26, Align8>);
+
+impl UlidStr {
+  /// Parses a ULID in either case and normalises it to uppercase
+  ///
+  /// The aliases `I` and `L` are read as `1`, and `O` as `0`. Returns `Err`
+  /// with the offset of the first invalid character. The first character must
+  /// not exceed `7`, since a ULID has 128 bits.
+  pub const fn parse(s: &str) -> Result<Self, DecodeError> {
+    let bytes = s.as_bytes();
+
+    if bytes.len() != 26 {
+      return Err(DecodeError::InvalidLength {
+        length: bytes.len(),
+        expected: 26,
+      });
+    }
+
+    let mut data = [0u8; 26];
+    let mut i = 0;
+
+    while i < 26 {
+      data[i] = match crockford_value(bytes[i]) {
+        Some(v) if i > 0 || v < 8 => ALPHABET[v as usize],
+        _ => return Err(DecodeError::InvalidCharacter { offset: i }),
+      };
+
+      i += 1;
+    }
+
+    // SAFETY: `data` only consists of ASCII characters from the alphabet
+    Ok(UlidStr(unsafe { FixedStr::from_bytes(data) }))
+  }
+
+  /// Encodes the ULID's big-endian bytes
+  pub const fn from_bytes(bytes: &[u8; 16]) -> Self {
+    let value = u128::from_be_bytes(*bytes);
+    let mut data = [0u8; 26];
+    let mut i = 0;
+
+    while i < 26 {
+      data[i] = ALPHABET[((value >> (5 * (25 - i))) & 0x1f) as usize];
+      i += 1;
+    }
+
+    // SAFETY: `data` only consists of ASCII characters from the alphabet
+    UlidStr(unsafe { FixedStr::from_bytes(data) })
+  }
+
+  /// Returns the ULID's big-endian bytes
+  pub fn to_bytes(&self) -> [u8; 16] {
+    let value = self.0.as_bytes().iter().fold(0u128, |value, &c| {
+      // The content was validated on construction
+      (value << 5) | crockford_value(c).unwrap_or(0) as u128
+    });
+
+    value.to_be_bytes()
+  }
+
+  /// Returns the timestamp in milliseconds since the Unix epoch, which is
+  /// stored in the first 48 bits
+  pub fn timestamp_ms(&self) -> u64 {
+    let bytes = self.to_bytes();
+    let mut timestamp = [0u8; 8];
+    timestamp[2..].copy_from_slice(&bytes[..6]);
+    u64::from_be_bytes(timestamp)
+  }
+
+  /// Converts UlidStr to `&str`
+  #[inline]
+  pub fn as_str(&self) -> &str {
+    self.0.as_str()
+  }
+
+  /// Returns the underlying FixedStr
+  #[inline]
+  pub fn as_fixed_str(&self) -> &FixedStr<26, Align8> {
+    &self.0
+  }
+}
+
+impl Hash for UlidStr {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.as_str().hash(state);
+  }
+}
+
+impl fmt::Display for UlidStr {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+impl fmt::Debug for UlidStr {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+impl FromStr for UlidStr {
+  type Err = DecodeError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Self::parse(s)
+  }
+}
+
+impl TryFrom<&str> for UlidStr {
+  type Error = DecodeError;
+
+  fn try_from(s: &str) -> Result<Self, Self::Error> {
+    Self::parse(s)
+  }
+}
+
+impl From<UlidStr> for FixedStr<26, Align8> {
+  fn from(id: UlidStr) -> Self {
+    id.0
+  }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for UlidStr {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    self.as_str().serialize(serializer)
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for UlidStr {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: serde::Deserializer<'de>,
+  {
+    let v = String::deserialize(deserializer)?;
+    UlidStr::parse(&v).map_err(serde::de::Error::custom)
+  }
+}
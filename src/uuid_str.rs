@@ -0,0 +1,210 @@
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+
+#[cfg(feature = "serde")]
+use std::string::String;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::Align8;
+use crate::DecodeError;
+use crate::FixedStr;
+use crate::codec::{hex_digit, hex_value};
+
+/// Offsets of the hyphens in the canonical form
+const HYPHENS: [usize; 4] = [8, 13, 18, 23];
+
+/// Validated UUID in its canonical form, e.g.
+/// `67e55044-10b1-426f-9247-bb680e5fe0c8`
+///
+/// The string is always hyphenated and lowercase, such that the lexicographic
+/// order matches the order of the UUID's bytes.
+///
+/// # Usage
+/// ```rust
+/// # use qstr::UuidStr;
+/// let id = UuidStr::parse("67E55044-10B1-426F-9247-BB680E5FE0C8").unwrap();
+/// assert_eq!(id.as_str(), "67e55044-10b1-426f-9247-bb680e5fe0c8");
+/// assert_eq!(UuidStr::from_bytes(&id.to_bytes()), id);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct UuidStr(FixedStr<36, Align8>);
+
+impl UuidStr {
+  /// Parses a hyphenated UUID in either case and normalises it to lowercase
+  ///
+  /// Returns `Err` with the offset of the first invalid character.
+  pub const fn parse(s: &str) -> Result<Self, DecodeError> {
+    let bytes = s.as_bytes();
+
+    if bytes.len() != 36 {
+      return Err(DecodeError::InvalidLength {
+        length: bytes.len(),
+        expected: 36,
+      });
+    }
+
+    let mut data = [0u8; 36];
+    let mut i = 0;
+
+    while i < 36 {
+      let c = bytes[i];
+
+      data[i] = if is_hyphen(i) {
+        if c != b'-' {
+          return Err(DecodeError::InvalidCharacter { offset: i });
+        }
+
+        c
+      } else {
+        match hex_value(c) {
+          Some(v) => hex_digit(v),
+          None => return Err(DecodeError::InvalidCharacter { offset: i }),
+        }
+      };
+
+      i += 1;
+    }
+
+    // SAFETY: `data` only consists of ASCII hex digits and hyphens
+    Ok(UuidStr(unsafe { FixedStr::from_bytes(data) }))
+  }
+
+  /// Formats the UUID's bytes in the canonical form
+  pub const fn from_bytes(bytes: &[u8; 16]) -> Self {
+    let mut data = [b'-'; 36];
+    let mut offset = 0;
+    let mut i = 0;
+
+    while i < 16 {
+      if is_hyphen(offset) {
+        offset += 1;
+      }
+
+      data[offset] = hex_digit(bytes[i] >> 4);
+      data[offset + 1] = hex_digit(bytes[i] & 0xf);
+      offset += 2;
+      i += 1;
+    }
+
+    // SAFETY: `data` only consists of ASCII hex digits and hyphens
+    UuidStr(unsafe { FixedStr::from_bytes(data) })
+  }
+
+  /// Returns the UUID's bytes
+  pub fn to_bytes(&self) -> [u8; 16] {
+    let mut bytes = [0u8; 16];
+    let digits = self.0.as_bytes().iter().filter(|&&c| c != b'-');
+
+    for (i, c) in digits.enumerate() {
+      // The content was validated on construction
+      let v = hex_value(*c).unwrap_or(0);
+      bytes[i / 2] |= if i % 2 == 0 { v << 4 } else { v };
+    }
+
+    bytes
+  }
+
+  /// Converts UuidStr to `&str`
+  #[inline]
+  pub fn as_str(&self) -> &str {
+    self.0.as_str()
+  }
+
+  /// Returns the underlying FixedStr
+  #[inline]
+  pub fn as_fixed_str(&self) -> &FixedStr<36, Align8> {
+    &self.0
+  }
+}
+
+const fn is_hyphen(offset: usize) -> bool {
+  let mut i = 0;
+
+  while i < HYPHENS.len() {
+    if HYPHENS[i] == offset {
+      return true;
+    }
+
+    i += 1;
+  }
+
+  false
+}
+
+impl Hash for UuidStr {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.as_str().hash(state);
+  }
+}
+
+impl fmt::Display for UuidStr {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+impl fmt::Debug for UuidStr {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+impl FromStr for UuidStr {
+  type Err = DecodeError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Self::parse(s)
+  }
+}
+
+impl TryFrom<&str> for UuidStr {
+  type Error = DecodeError;
+
+  fn try_from(s: &str) -> Result<Self, Self::Error> {
+    Self::parse(s)
+  }
+}
+
+impl From<UuidStr> for FixedStr<36, Align8> {
+  fn from(id: UuidStr) -> Self {
+    id.0
+  }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for UuidStr {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    self.as_str().serialize(serializer)
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for UuidStr {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: serde::Deserializer<'de>,
+  {
+    let v = String::deserialize(deserializer)?;
+    UuidStr::parse(&v).map_err(serde::de::Error::custom)
+  }
+}
+
+#[cfg(feature = "uuid")]
+impl From<uuid::Uuid> for UuidStr {
+  fn from(id: uuid::Uuid) -> Self {
+    Self::from_bytes(id.as_bytes())
+  }
+}
+
+#[cfg(feature = "uuid")]
+impl From<UuidStr> for uuid::Uuid {
+  fn from(id: UuidStr) -> Self {
+    uuid::Uuid::from_bytes(id.to_bytes())
+  }
+}
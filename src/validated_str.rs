@@ -0,0 +1,279 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::str::FromStr;
+
+#[cfg(feature = "serde")]
+use std::string::String;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::BoundedStr;
+use crate::ValidationError;
+use crate::{AsciiAlphanumeric, DnsLabel, Identifier, Lowercase};
+
+/// Rule that strings must satisfy to be stored in a [ValidatedStr]
+///
+/// Trait methods cannot be `const` on stable Rust, so the generic API only
+/// validates at runtime. The ready-made validators additionally expose their
+/// check as an inherent `const fn`, e.g.
+/// [AsciiAlphanumeric::validate](crate::AsciiAlphanumeric::validate), through
+/// which `ValidatedStr::const_from` builds values in a const context. Custom
+/// validators have no such constructor.
+pub trait Validator {
+  /// Error returned for rejected strings
+  type Error;
+
+  /// Checks whether `s` is valid
+  fn validate(s: &str) -> Result<(), Self::Error>;
+}
+
+/// [BoundedStr] whose content always satisfies the validator `V`
+///
+/// The content is checked on construction, on deserialisation and by every
+/// mutating method, which leaves the string unchanged on failure.
+///
+/// # Usage
+/// ```rust
+/// # use qstr::{Align16, DnsLabel, ValidatedStr};
+/// type Label = ValidatedStr<DnsLabel, 15, Align16>;
+///
+/// let mut label = Label::try_from("eu-west").unwrap();
+/// assert!(label.push_str("_1").is_err());
+/// assert_eq!(label.as_str(), "eu-west");
+///
+/// label.push_str("-1").unwrap();
+/// assert_eq!(label.as_str(), "eu-west-1");
+/// assert!(Label::try_from("-eu").is_err());
+///
+/// const DEFAULT: Label = Label::const_from("eu-west-1");
+/// assert_eq!(DEFAULT.as_str(), "eu-west-1");
+/// ```
+///
+/// Invalid constants are rejected at compile time:
+/// ```compile_fail
+/// # use qstr::{Align16, DnsLabel, ValidatedStr};
+/// const LABEL: ValidatedStr<DnsLabel, 15, Align16> = ValidatedStr::const_from("-eu");
+/// ```
+pub struct ValidatedStr<V, const N: usize, Alignment> {
+  inner: BoundedStr<N, Alignment>,
+  validator: PhantomData<V>,
+}
+
+impl<V: Validator, const N: usize, Alignment> ValidatedStr<V, N, Alignment> {
+  /// Validates `s` and copies it into a ValidatedStr
+  ///
+  /// Returns `Err` if `s` exceeds the capacity or is rejected by `V`.
+  pub fn try_from(s: &str) -> Result<Self, ValidationError<V::Error>> {
+    let inner = BoundedStr::try_from(s)?;
+    Self::try_from_bounded(inner)
+  }
+
+  /// Validates the content of a BoundedStr
+  pub fn try_from_bounded(
+    inner: BoundedStr<N, Alignment>,
+  ) -> Result<Self, ValidationError<V::Error>> {
+    V::validate(inner.as_str()).map_err(ValidationError::Invalid)?;
+
+    Ok(Self {
+      inner,
+      validator: PhantomData,
+    })
+  }
+
+  /// Appends a string slice if the result is still valid
+  ///
+  /// Returns `Err` and leaves the string unchanged if there is not enough
+  /// capacity or the result is rejected by `V`.
+  pub fn push_str(&mut self, s: &str) -> Result<(), ValidationError<V::Error>>
+  where
+    Alignment: Clone,
+  {
+    let mut inner = self.inner.clone();
+    inner.push_str(s)?;
+    *self = Self::try_from_bounded(inner)?;
+
+    Ok(())
+  }
+
+  /// Appends a single character if the result is still valid
+  ///
+  /// Returns `Err` and leaves the string unchanged if there is not enough
+  /// capacity or the result is rejected by `V`.
+  pub fn push(&mut self, c: char) -> Result<(), ValidationError<V::Error>>
+  where
+    Alignment: Clone,
+  {
+    let mut buf = [0u8; 4];
+    self.push_str(c.encode_utf8(&mut buf))
+  }
+
+  /// Replaces the content if `s` is valid
+  ///
+  /// Returns `Err` and leaves the string unchanged if `s` exceeds the capacity
+  /// or is rejected by `V`.
+  pub fn set(&mut self, s: &str) -> Result<(), ValidationError<V::Error>> {
+    *self = Self::try_from(s)?;
+    Ok(())
+  }
+}
+
+/// Implements `const_from` for validators with an inherent `const fn validate`
+macro_rules! impl_const_from {
+  ($($v:ty),*) => {
+    $(
+      impl<const N: usize, Alignment> ValidatedStr<$v, N, Alignment> {
+        /// Builds a ValidatedStr in a const context
+        ///
+        /// Panics if `s` exceeds the capacity or is rejected by the validator,
+        /// which fails compilation when evaluated in a constant.
+        pub const fn const_from(s: &str) -> Self {
+          assert!(<$v>::validate(s).is_ok(), "String is rejected by the validator");
+
+          Self {
+            inner: BoundedStr::const_from(s),
+            validator: PhantomData,
+          }
+        }
+      }
+    )*
+  };
+}
+
+impl_const_from!(AsciiAlphanumeric, Identifier, DnsLabel, Lowercase);
+
+impl<V, const N: usize, Alignment> ValidatedStr<V, N, Alignment> {
+  /// Returns string length
+  #[inline]
+  pub fn len(&self) -> usize {
+    self.inner.len()
+  }
+
+  /// Checks if the ValidatedStr is empty
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    self.inner.is_empty()
+  }
+
+  /// Converts ValidatedStr to `&str`
+  #[inline]
+  pub fn as_str(&self) -> &str {
+    self.inner.as_str()
+  }
+
+  /// Returns the underlying BoundedStr
+  #[inline]
+  pub fn as_bounded_str(&self) -> &BoundedStr<N, Alignment> {
+    &self.inner
+  }
+}
+
+impl<V, const N: usize, Alignment: Clone> Clone for ValidatedStr<V, N, Alignment> {
+  fn clone(&self) -> Self {
+    Self {
+      inner: self.inner.clone(),
+      validator: PhantomData,
+    }
+  }
+}
+
+impl<V, const N: usize, Alignment: Copy> Copy for ValidatedStr<V, N, Alignment> {}
+
+impl<V, const N: usize, Alignment> PartialEq for ValidatedStr<V, N, Alignment> {
+  fn eq(&self, other: &Self) -> bool {
+    self.inner == other.inner
+  }
+}
+
+impl<V, const N: usize, Alignment> Eq for ValidatedStr<V, N, Alignment> {}
+
+impl<V, const N: usize, Alignment> PartialOrd for ValidatedStr<V, N, Alignment> {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl<V, const N: usize, Alignment> Ord for ValidatedStr<V, N, Alignment> {
+  fn cmp(&self, other: &Self) -> Ordering {
+    self.inner.cmp(&other.inner)
+  }
+}
+
+impl<V, const N: usize, Alignment> Hash for ValidatedStr<V, N, Alignment> {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.as_str().hash(state);
+  }
+}
+
+impl<V, const N: usize, Alignment> fmt::Display for ValidatedStr<V, N, Alignment> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+impl<V, const N: usize, Alignment> fmt::Debug for ValidatedStr<V, N, Alignment> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+impl<V: Validator, const N: usize, Alignment> FromStr for ValidatedStr<V, N, Alignment> {
+  type Err = ValidationError<V::Error>;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Self::try_from(s)
+  }
+}
+
+impl<V: Validator, const N: usize, Alignment> TryFrom<&str> for ValidatedStr<V, N, Alignment> {
+  type Error = ValidationError<V::Error>;
+
+  fn try_from(s: &str) -> Result<Self, Self::Error> {
+    Self::try_from(s)
+  }
+}
+
+impl<V: Validator, const N: usize, Alignment> TryFrom<BoundedStr<N, Alignment>>
+  for ValidatedStr<V, N, Alignment>
+{
+  type Error = ValidationError<V::Error>;
+
+  fn try_from(s: BoundedStr<N, Alignment>) -> Result<Self, Self::Error> {
+    Self::try_from_bounded(s)
+  }
+}
+
+impl<V, const N: usize, Alignment> From<ValidatedStr<V, N, Alignment>>
+  for BoundedStr<N, Alignment>
+{
+  fn from(s: ValidatedStr<V, N, Alignment>) -> Self {
+    s.inner
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<V, const N: usize, Alignment> Serialize for ValidatedStr<V, N, Alignment> {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    self.as_str().serialize(serializer)
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, V: Validator, const N: usize, Alignment> Deserialize<'de>
+  for ValidatedStr<V, N, Alignment>
+where
+  V::Error: fmt::Display,
+{
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: serde::Deserializer<'de>,
+  {
+    let s = String::deserialize(deserializer)?;
+    ValidatedStr::try_from(s.as_str()).map_err(serde::de::Error::custom)
+  }
+}
@@ -0,0 +1,156 @@
+//! Ready-made [Validator] implementations
+//!
+//! Trait methods cannot be `const` on stable Rust, so every validator also
+//! provides its check as an inherent `const fn validate`, which can be used in
+//! constant expressions.
+
+use crate::InvalidStr;
+use crate::Validator;
+
+/// Non-empty string of ASCII letters and digits
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct AsciiAlphanumeric;
+
+impl AsciiAlphanumeric {
+  /// Checks that `s` is non-empty and only consists of ASCII letters and digits
+  pub const fn validate(s: &str) -> Result<(), InvalidStr> {
+    let bytes = s.as_bytes();
+
+    if bytes.is_empty() {
+      return Err(InvalidStr { offset: 0 });
+    }
+
+    let mut i = 0;
+
+    while i < bytes.len() {
+      if !bytes[i].is_ascii_alphanumeric() {
+        return Err(InvalidStr { offset: i });
+      }
+
+      i += 1;
+    }
+
+    Ok(())
+  }
+}
+
+impl Validator for AsciiAlphanumeric {
+  type Error = InvalidStr;
+
+  fn validate(s: &str) -> Result<(), InvalidStr> {
+    AsciiAlphanumeric::validate(s)
+  }
+}
+
+/// ASCII identifier as used by most programming languages, i.e. a letter or
+/// underscore followed by letters, digits and underscores
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Identifier;
+
+impl Identifier {
+  /// Checks that `s` is a non-empty ASCII identifier
+  pub const fn validate(s: &str) -> Result<(), InvalidStr> {
+    let bytes = s.as_bytes();
+
+    if bytes.is_empty() {
+      return Err(InvalidStr { offset: 0 });
+    }
+
+    let mut i = 0;
+
+    while i < bytes.len() {
+      let c = bytes[i];
+      let valid = c == b'_' || c.is_ascii_alphabetic() || (i > 0 && c.is_ascii_digit());
+
+      if !valid {
+        return Err(InvalidStr { offset: i });
+      }
+
+      i += 1;
+    }
+
+    Ok(())
+  }
+}
+
+impl Validator for Identifier {
+  type Error = InvalidStr;
+
+  fn validate(s: &str) -> Result<(), InvalidStr> {
+    Identifier::validate(s)
+  }
+}
+
+/// DNS label (RFC 1123): 1 to 63 ASCII letters, digits and hyphens, neither
+/// starting nor ending with a hyphen
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct DnsLabel;
+
+impl DnsLabel {
+  /// Maximum length of a DNS label in bytes
+  pub const MAX_LEN: usize = 63;
+
+  /// Checks that `s` is a valid DNS label
+  pub const fn validate(s: &str) -> Result<(), InvalidStr> {
+    let bytes = s.as_bytes();
+
+    if bytes.is_empty() {
+      return Err(InvalidStr { offset: 0 });
+    }
+
+    let mut i = 0;
+
+    while i < bytes.len() {
+      let c = bytes[i];
+      let hyphen = c == b'-' && i > 0 && i < bytes.len() - 1;
+
+      if i >= Self::MAX_LEN || !(c.is_ascii_alphanumeric() || hyphen) {
+        return Err(InvalidStr { offset: i });
+      }
+
+      i += 1;
+    }
+
+    Ok(())
+  }
+}
+
+impl Validator for DnsLabel {
+  type Error = InvalidStr;
+
+  fn validate(s: &str) -> Result<(), InvalidStr> {
+    DnsLabel::validate(s)
+  }
+}
+
+/// String without ASCII uppercase letters
+///
+/// Non-ASCII characters are accepted as-is.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Lowercase;
+
+impl Lowercase {
+  /// Checks that `s` does not contain ASCII uppercase letters
+  pub const fn validate(s: &str) -> Result<(), InvalidStr> {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+
+    while i < bytes.len() {
+      if bytes[i].is_ascii_uppercase() {
+        return Err(InvalidStr { offset: i });
+      }
+
+      i += 1;
+    }
+
+    Ok(())
+  }
+}
+
+impl Validator for Lowercase {
+  type Error = InvalidStr;
+
+  fn validate(s: &str) -> Result<(), InvalidStr> {
+    Lowercase::validate(s)
+  }
+}